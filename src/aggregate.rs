@@ -0,0 +1,152 @@
+//! Incremental computations that are kept consistent with the contents of a window.
+//!
+//! An [`Aggregator`] is notified about every element that enters or leaves the window, so it can
+//! maintain its result without iterating over the whole window.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::SlidingWindow;
+//! use sliding_window::aggregate::Aggregator;
+//!
+//! #[derive(Default)]
+//! struct Sum(u32);
+//!
+//! impl Aggregator<u32> for Sum {
+//!     fn on_insert(&mut self, item: &u32) {
+//!         self.0 += *item;
+//!     }
+//!
+//!     fn on_evict(&mut self, item: &u32) {
+//!         self.0 -= *item;
+//!     }
+//! }
+//!
+//! let mut sw = SlidingWindow::<u32, 3>::new().with_aggregator(Sum::default());
+//!
+//! sw.insert(1);
+//! sw.insert(2);
+//! sw.insert(3);
+//! sw.insert(4);
+//!
+//! assert_eq!(9, sw.aggregator().0);
+//! ```
+
+use crate::SlidingWindow;
+
+/// An incremental computation over the contents of a window.
+pub trait Aggregator<IT> {
+    /// Called after `item` has been inserted into the window.
+    fn on_insert(&mut self, item: &IT);
+
+    /// Called when `item` is removed from the window.
+    fn on_evict(&mut self, item: &IT);
+}
+
+/// A sliding window that keeps an [`Aggregator`] up to date with its contents.
+///
+/// Returned by [`SlidingWindow::with_aggregator`].
+pub struct Aggregated<IT, const N: usize, A> {
+    window: SlidingWindow<IT, N>,
+    aggregator: A
+}
+
+impl<IT, const N: usize, A> Aggregated<IT, N, A>
+    where
+        A: Aggregator<IT> {
+
+    /// Insert an element into the window and notify the aggregator.
+    ///
+    /// If the window is full, this method will remove and return the oldest element. The
+    /// aggregator is notified about the removal before the insertion.
+    pub fn insert(&mut self, t: IT) -> Option<IT> {
+        let old = self.window.insert(t);
+
+        if let Some(old) = old.as_ref() {
+            self.aggregator.on_evict(old);
+        }
+        self.aggregator.on_insert(&self.window[self.window.count() - 1]);
+
+        old
+    }
+
+    /// Removes all elements from the window, notifying the aggregator about each of them.
+    pub fn clear(&mut self) {
+        for item in self.window.iter() {
+            self.aggregator.on_evict(item);
+        }
+        self.window.clear();
+    }
+
+    /// Returns the underlying window.
+    pub fn window(&self) -> &SlidingWindow<IT, N> {
+        &self.window
+    }
+
+    /// Returns the aggregator.
+    pub fn aggregator(&self) -> &A {
+        &self.aggregator
+    }
+
+    /// Splits the object into the underlying window and the aggregator.
+    pub fn into_parts(self) -> (SlidingWindow<IT, N>, A) {
+        (self.window, self.aggregator)
+    }
+}
+
+impl<IT, const N: usize> SlidingWindow<IT, N> {
+    /// Wraps the window so that `aggregator` is notified about every change to its contents.
+    ///
+    /// The aggregator is notified about the elements that are already in the window.
+    pub fn with_aggregator<A>(self, mut aggregator: A) -> Aggregated<IT, N, A>
+        where
+            A: Aggregator<IT> {
+        for item in self.iter() {
+            aggregator.on_insert(item);
+        }
+
+        Aggregated {
+            window: self,
+            aggregator
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Default)]
+    struct Log {
+        inserted: Vec<u32>,
+        evicted: Vec<u32>
+    }
+
+    impl Aggregator<u32> for Log {
+        fn on_insert(&mut self, item: &u32) {
+            self.inserted.push(*item);
+        }
+
+        fn on_evict(&mut self, item: &u32) {
+            self.evicted.push(*item);
+        }
+    }
+
+    #[test]
+    fn notifies_aggregator() {
+        let mut sw: SlidingWindow<u32, 2> = SlidingWindow::new();
+        sw.insert(1);
+
+        let mut sw = sw.with_aggregator(Log::default());
+        assert_eq!(&[1], &sw.aggregator().inserted[..]);
+
+        sw.insert(2);
+        assert_eq!(Some(1), sw.insert(3));
+        assert_eq!(&[1, 2, 3], &sw.aggregator().inserted[..]);
+        assert_eq!(&[1], &sw.aggregator().evicted[..]);
+
+        sw.clear();
+        assert_eq!(0, sw.window().count());
+        assert_eq!(&[1, 2, 3], &sw.aggregator().evicted[..]);
+    }
+}
@@ -0,0 +1,221 @@
+//! Threshold crossing and change point detection.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::SlidingWindow;
+//! use sliding_window::analysis::{Crossing, Direction, Shift};
+//!
+//! let mut window: SlidingWindow<f32, 4> = SlidingWindow::new();
+//! for &sample in [0.0, 1.0, 3.0, 2.0, 0.5].iter() {
+//!     window.insert(sample);
+//! }
+//!
+//! // [1, 3, 2, 0.5]
+//! let mut crossings = window.crossings(1.5);
+//! assert_eq!(Some(Crossing { index: 1, direction: Direction::Rising }), crossings.next());
+//! assert_eq!(Some(Crossing { index: 3, direction: Direction::Falling }), crossings.next());
+//! assert_eq!(None, crossings.next());
+//!
+//! let shift = window.mean_shift(2);
+//! assert_eq!(Some(Shift { magnitude: 0.75, direction: Direction::Falling }), shift);
+//! ```
+
+use crate::{SlidingWindowBase, Storage, Iter};
+use crate::num::Sample;
+use crate::view::WindowView;
+
+/// Direction of a threshold crossing or a mean shift.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// The signal went from below the threshold to at or above it, or its mean did not decrease.
+    Rising,
+
+    /// The signal went from at or above the threshold to below it, or its mean decreased.
+    Falling
+}
+
+/// A point where the window's contents cross a threshold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crossing {
+    /// Logical index of the first element on the new side of the threshold.
+    pub index: usize,
+
+    /// Whether the signal was rising or falling.
+    pub direction: Direction
+}
+
+/// A change in the mean of the window's contents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Shift<T> {
+    /// Absolute difference between the means.
+    pub magnitude: T,
+
+    /// Whether the mean increased or decreased.
+    pub direction: Direction
+}
+
+/// Iterator over threshold crossings.
+///
+/// Returned by [`SlidingWindow::crossings`](crate::SlidingWindow::crossings).
+pub struct Crossings<'a, IT> {
+    iter: Iter<'a, IT>,
+    threshold: IT,
+    above: Option<bool>,
+    idx: usize
+}
+
+impl<'a, IT> Iterator for Crossings<'a, IT>
+    where
+        IT: PartialOrd {
+    type Item = Crossing;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in &mut self.iter {
+            let idx = self.idx;
+            self.idx += 1;
+
+            let above = *item >= self.threshold;
+            let previous = self.above.replace(above);
+
+            let direction = match previous {
+                Some(false) if above => Direction::Rising,
+                Some(true) if !above => Direction::Falling,
+                _ => continue
+            };
+
+            return Some(Crossing { index: idx, direction });
+        }
+
+        None
+    }
+}
+
+/// Returns the mean of the elements of `view`, or `None` if it is empty or its length is not
+/// representable by `IT`.
+fn mean<IT>(view: WindowView<'_, IT>) -> Option<IT>
+    where
+        IT: Sample {
+    if view.is_empty() {
+        return None;
+    }
+
+    let count = IT::from_usize(view.len())?;
+    let sum = view.iter().fold(IT::ZERO, |acc, &item| acc + item);
+    Some(sum / count)
+}
+
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT>,
+        IT: PartialOrd {
+
+    /// Returns an iterator over the points where the window's contents cross `threshold`.
+    ///
+    /// Elements equal to the threshold count as being above it. Each crossing is reported at the
+    /// index of the first element on the new side, so the oldest element is never reported.
+    pub fn crossings(&self, threshold: IT) -> Crossings<'_, IT> {
+        Crossings {
+            iter: self.iter(),
+            threshold,
+            above: None,
+            idx: 0
+        }
+    }
+}
+
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT>,
+        IT: Sample {
+
+    /// Compares the means of the elements older and newer than `split`.
+    ///
+    /// The shift is returned as a magnitude and a direction, so that it can be represented by
+    /// unsigned types, too. Equal means are reported as [`Direction::Rising`] with a magnitude of
+    /// zero. Returns `None` if either part is empty, or if the number of elements in it is not
+    /// representable by `IT`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `split` is greater than [`count`](SlidingWindowBase::count).
+    pub fn mean_shift(&self, split: usize) -> Option<Shift<IT>> {
+        let (older, newer) = self.split_at(split);
+        let (older, newer) = (mean(older)?, mean(newer)?);
+
+        Some(if newer < older {
+            Shift { magnitude: older - newer, direction: Direction::Falling }
+        } else {
+            Shift { magnitude: newer - older, direction: Direction::Rising }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Crossing, Direction, Shift};
+    use crate::SlidingWindow;
+
+    #[test]
+    fn crossings() {
+        let mut sw: SlidingWindow<i32, 6> = SlidingWindow::new();
+
+        assert_eq!(0, sw.crossings(0).count());
+
+        for &sample in [9, -1, 1, 0, -2, -3, 5].iter() {
+            sw.insert(sample);
+        }
+
+        // [-1, 1, 0, -2, -3, 5]
+        let expected = [
+            Crossing { index: 1, direction: Direction::Rising },
+            Crossing { index: 3, direction: Direction::Falling },
+            Crossing { index: 5, direction: Direction::Rising }
+        ];
+
+        assert!(sw.crossings(0).eq(expected.iter().copied()));
+        assert!(sw.crossings(10).eq(core::iter::empty()));
+    }
+
+    #[test]
+    fn mean_shift() {
+        let mut sw: SlidingWindow<f32, 4> = SlidingWindow::new();
+
+        assert_eq!(None, sw.mean_shift(0));
+
+        for &sample in [7.0, 1.0, 3.0, 5.0, 11.0].iter() {
+            sw.insert(sample);
+        }
+
+        // [1, 3, 5, 11]
+        assert_eq!(Some(Shift { magnitude: 6.0, direction: Direction::Rising }), sw.mean_shift(2));
+        assert_eq!(Some(Shift { magnitude: 8.0, direction: Direction::Rising }), sw.mean_shift(3));
+        assert_eq!(None, sw.mean_shift(4));
+    }
+
+    #[test]
+    fn falling_unsigned_mean_shift() {
+        let mut sw: SlidingWindow<u16, 4> = SlidingWindow::new();
+
+        for &sample in [10, 10, 2, 2].iter() {
+            sw.insert(sample);
+        }
+
+        assert_eq!(Some(Shift { magnitude: 8, direction: Direction::Falling }), sw.mean_shift(2));
+        // The mean of [10, 2, 2] is rounded down to 4
+        assert_eq!(Some(Shift { magnitude: 6, direction: Direction::Falling }), sw.mean_shift(1));
+    }
+
+    #[test]
+    fn mean_shift_count_not_representable() {
+        let mut sw: SlidingWindow<u8, 300> = SlidingWindow::new();
+
+        for _ in 0..300 {
+            sw.insert(0);
+        }
+
+        assert_eq!(Some(Shift { magnitude: 0, direction: Direction::Rising }), sw.mean_shift(150));
+        assert_eq!(None, sw.mean_shift(10));
+        assert_eq!(None, sw.mean_shift(290));
+    }
+}
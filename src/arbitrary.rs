@@ -0,0 +1,54 @@
+//! Random windows for property based testing with `proptest`.
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::collection::vec;
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use crate::SlidingWindow;
+
+impl<IT, const N: usize> Arbitrary for SlidingWindow<IT, N>
+    where
+        IT: Arbitrary + 'static {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates windows that are empty, partially filled or full, with their contents starting
+    /// at a random position of the internal array so that they may wrap around its end.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (vec(any::<IT>(), 0..N), vec(any::<IT>(), 0..=N))
+            .prop_map(|(offset, contents)| {
+                let mut window = Self::new();
+
+                // Inserting and removing elements moves the position of the oldest element.
+                let shift = offset.len();
+                for item in offset {
+                    window.insert(item);
+                }
+                window.truncate_oldest(shift);
+
+                for item in contents {
+                    window.insert(item);
+                }
+                window
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use crate::SlidingWindow;
+
+    proptest! {
+        #[test]
+        fn arbitrary_windows(mut window in any::<SlidingWindow<u8, 5>>()) {
+            prop_assert!(window.count() <= 5);
+            prop_assert_eq!(window.count(), window.iter().len());
+
+            let contents = window.iter().copied().collect::<Vec<_>>();
+            prop_assert_eq!(&contents[..], window.make_contiguous());
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Raw byte access to windows of plain data.
+
+use crate::{SlidingWindowBase, Storage};
+
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT>,
+        IT: bytemuck::Pod {
+
+    /// Returns the bytes of the elements as two slices, in the order of insertion.
+    ///
+    /// The second slice is empty unless the contents wrap around the end of the internal storage.
+    pub fn as_byte_slices(&self) -> (&[u8], &[u8]) {
+        let (older, newer) = self.as_slices();
+
+        (bytemuck::cast_slice(older), bytemuck::cast_slice(newer))
+    }
+
+    /// Returns the bytes of the elements as a single slice, in the order of insertion.
+    ///
+    /// The elements are moved to be contiguous first, see
+    /// [`make_contiguous`](SlidingWindowBase::make_contiguous).
+    pub fn as_bytes(&mut self) -> &[u8] {
+        bytemuck::cast_slice(self.make_contiguous())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SlidingWindow;
+
+    #[test]
+    fn bytes() {
+        let mut sw: SlidingWindow<u16, 3> = SlidingWindow::new();
+
+        for i in 1..=4 {
+            sw.insert(i * 0x0101);
+        }
+
+        let (older, newer) = sw.as_byte_slices();
+        assert_eq!(&[2, 2, 3, 3], older);
+        assert_eq!(&[4, 4], newer);
+        assert_eq!(&[2, 2, 3, 3, 4, 4], sw.as_bytes());
+    }
+}
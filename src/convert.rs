@@ -0,0 +1,82 @@
+//! Conversions between sliding windows and collections of other crates.
+
+#[cfg(feature = "heapless")]
+use crate::SlidingWindow;
+#[cfg(feature = "alloc")]
+use crate::{SlidingWindowBase, Storage};
+
+#[cfg(feature = "heapless")]
+impl<IT, const N: usize> From<heapless::Vec<IT, N>> for SlidingWindow<IT, N> {
+    /// Creates a window from the elements of `vec`, the first element being the oldest.
+    fn from(vec: heapless::Vec<IT, N>) -> Self {
+        let mut window = Self::new();
+        for item in vec {
+            window.insert(item);
+        }
+        window
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<IT, const N: usize> From<SlidingWindow<IT, N>> for heapless::Vec<IT, N> {
+    /// Moves the elements of `window` into a vector, in the order of insertion.
+    fn from(mut window: SlidingWindow<IT, N>) -> Self {
+        let mut vec = Self::new();
+        while let Some(item) = window.pop_oldest() {
+            // The vector has the same capacity as the window.
+            if vec.push(item).is_err() {
+                unreachable!();
+            }
+        }
+        vec
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT>,
+        IT: Clone {
+
+    /// Copies the elements of the window into a vector, in the order of insertion.
+    pub fn to_vec(&self) -> alloc::vec::Vec<IT> {
+        let (older, newer) = self.as_slices();
+
+        let mut vec = alloc::vec::Vec::with_capacity(self.count());
+        vec.extend_from_slice(older);
+        vec.extend_from_slice(newer);
+        vec
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SlidingWindow;
+
+    #[test]
+    #[cfg(feature = "heapless")]
+    fn heapless_vec() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+        for i in 1..=6 {
+            sw.insert(i);
+        }
+
+        let vec: heapless::Vec<_, 4> = sw.into();
+        assert_eq!(&[3, 4, 5, 6], &vec[..]);
+
+        let sw: SlidingWindow<_, 4> = vec.into();
+        assert_eq!(4, sw.count());
+        assert_eq!(3, sw[0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+        for i in 1..=6 {
+            sw.insert(i);
+        }
+
+        assert_eq!(vec![3, 4, 5, 6], sw.to_vec());
+    }
+}
@@ -0,0 +1,180 @@
+//! Sliding window that stores runs of repeated values in a single slot.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::dedup::DedupWindow;
+//!
+//! let mut window: DedupWindow<char, 2> = DedupWindow::new();
+//!
+//! window.insert('a');
+//! window.insert('a');
+//! window.insert('b');
+//! window.insert('b');
+//! window.insert('b');
+//!
+//! assert!(window.runs().eq([(&'a', 2), (&'b', 3)]));
+//! assert_eq!(5, window.len());
+//!
+//! // A new value evicts the oldest run
+//! assert_eq!(Some(('a', 2)), window.insert('c'));
+//! ```
+
+use crate::{SlidingWindow, Iter};
+
+/// A sliding window that holds the `N` most recent runs of equal values.
+///
+/// Inserting a value equal to the newest one increases the length of the newest run instead of
+/// taking a new slot.
+pub struct DedupWindow<T, const N: usize> {
+    window: SlidingWindow<(T, usize), N>
+}
+
+impl<T, const N: usize> Default for DedupWindow<T, N> {
+    fn default() -> Self {
+        Self {
+            window: SlidingWindow::new()
+        }
+    }
+}
+
+impl<T, const N: usize> DedupWindow<T, N>
+    where
+        T: PartialEq {
+
+    /// Returns an empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a value into the window.
+    ///
+    /// If the value equals the newest one, the length of the newest run is increased. Otherwise a
+    /// new run is started and, if the window is full, the oldest run is removed and returned as a
+    /// `(value, run_length)` pair.
+    pub fn insert(&mut self, value: T) -> Option<(T, usize)> {
+        let (older, newer) = self.window.as_mut_slices();
+        if let Some((newest, run_length)) = newer.last_mut().or_else(|| older.last_mut()) {
+            if *newest == value {
+                *run_length = run_length.saturating_add(1);
+                return None;
+            }
+        }
+
+        self.window.insert((value, 1))
+    }
+
+    /// Removes all values from the window.
+    pub fn clear(&mut self) {
+        self.window.clear();
+    }
+
+    /// Returns the number of runs stored in the window.
+    pub fn count(&self) -> usize {
+        self.window.count()
+    }
+
+    /// Returns the total number of values in all runs.
+    pub fn len(&self) -> usize {
+        self.runs().map(|(_, run_length)| run_length).sum()
+    }
+
+    /// Returns `true` if the window holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.window.count() == 0
+    }
+
+    /// Returns an iterator over the `(value, run_length)` pairs, starting with the oldest.
+    pub fn runs(&self) -> Runs<'_, T> {
+        Runs {
+            iter: self.window.iter()
+        }
+    }
+
+    /// Returns an iterator that repeats every value by the length of its run, starting with the
+    /// oldest.
+    pub fn iter(&self) -> Expand<'_, T> {
+        Expand {
+            iter: self.window.iter(),
+            current: None
+        }
+    }
+
+    /// Returns the underlying window.
+    pub fn window(&self) -> &SlidingWindow<(T, usize), N> {
+        &self.window
+    }
+}
+
+/// Iterator over runs of equal values.
+///
+/// Returned by [`DedupWindow::runs`].
+pub struct Runs<'a, T> {
+    iter: Iter<'a, (T, usize)>
+}
+
+impl<'a, T> Iterator for Runs<'a, T> {
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, run_length)| (value, *run_length))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Runs<'a, T> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Iterator that repeats each value by the length of its run.
+///
+/// Returned by [`DedupWindow::iter`].
+pub struct Expand<'a, T> {
+    iter: Iter<'a, (T, usize)>,
+    current: Option<(&'a T, usize)>
+}
+
+impl<'a, T> Iterator for Expand<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.current {
+                Some((value, remaining)) if remaining > 0 => {
+                    self.current = Some((value, remaining - 1));
+                    return Some(value);
+                }
+                _ => {
+                    let (value, run_length) = self.iter.next()?;
+                    self.current = Some((value, *run_length));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn runs() {
+        let mut window: DedupWindow<u8, 3> = DedupWindow::new();
+
+        assert!(window.is_empty());
+        for &state in [1, 1, 2, 2, 2, 1, 3, 3].iter() {
+            window.insert(state);
+        }
+
+        // The oldest run of 1s has been evicted
+        assert!(window.runs().eq([(&2, 3), (&1, 1), (&3, 2)]));
+        assert_eq!(3, window.count());
+        assert_eq!(6, window.len());
+        assert_eq!(vec![2, 2, 2, 1, 3, 3], window.iter().copied().collect::<Vec<_>>());
+    }
+}
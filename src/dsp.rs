@@ -0,0 +1,444 @@
+//! Signal processing helpers that treat the window as a delay line.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::SlidingWindow;
+//!
+//! // A 3-tap moving average filter
+//! let taps = [1.0 / 3.0; 3];
+//! let mut delay_line: SlidingWindow<f32, 3> = SlidingWindow::new();
+//!
+//! delay_line.insert(3.0);
+//! delay_line.insert(6.0);
+//! delay_line.insert(9.0);
+//!
+//! assert!((delay_line.convolve(&taps) - 6.0).abs() < 1e-6);
+//! ```
+
+use crate::{SlidingWindowBase, Storage};
+use crate::num::{Real, Sample};
+use crate::view::WindowView;
+
+/// A sequence stored as two contiguous parts.
+type Split<'a, T> = (&'a [T], &'a [T]);
+
+/// Returns the dot product of the common prefix of two slices.
+fn dot<T>(a: &[T], b: &[T]) -> T
+    where
+        T: Sample {
+    a.iter().zip(b.iter()).fold(T::ZERO, |acc, (&a, &b)| acc + a * b)
+}
+
+/// Returns the dot product of the common prefix of two split sequences, processing them in
+/// contiguous runs.
+fn dot_split<T>(mut a: Split<'_, T>, mut b: Split<'_, T>) -> T
+    where
+        T: Sample {
+    let mut acc = T::ZERO;
+    loop {
+        if a.0.is_empty() {
+            a = (a.1, &[]);
+        }
+        if b.0.is_empty() {
+            b = (b.1, &[]);
+        }
+
+        let len = core::cmp::min(a.0.len(), b.0.len());
+        if len == 0 {
+            return acc;
+        }
+
+        acc = acc + dot(&a.0[..len], &b.0[..len]);
+        a.0 = &a.0[len..];
+        b.0 = &b.0[len..];
+    }
+}
+
+/// Removes the first `n` elements of a split sequence.
+fn skip<T>((first, second): Split<'_, T>, n: usize) -> Split<'_, T> {
+    if n < first.len() {
+        (&first[n..], second)
+    } else {
+        (&second[n - first.len()..], &[])
+    }
+}
+
+/// Returns the value at a fractional position of `view`, linearly interpolated between the
+/// neighbouring elements.
+fn interpolate<IT>(view: WindowView<'_, IT>, pos: f32) -> Option<IT>
+    where
+        IT: Real {
+    let count = view.len();
+    if count == 0 || !(0.0..=(count - 1) as f32).contains(&pos) {
+        return None;
+    }
+
+    let idx = pos as usize;
+    let value = view[idx];
+    if idx == count - 1 {
+        return Some(value);
+    }
+
+    let frac = pos - idx as f32;
+    Some(value + (view[idx + 1] - value) * IT::from_f32(frac))
+}
+
+/// Iterator over linearly interpolated values at evenly spaced positions.
+///
+/// Returned by [`SlidingWindow::resample`](crate::SlidingWindow::resample).
+pub struct Resample<'a, IT> {
+    window: WindowView<'a, IT>,
+    step: f32,
+    n: usize
+}
+
+impl<'a, IT> Iterator for Resample<'a, IT>
+    where
+        IT: Real {
+    type Item = IT;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = interpolate(self.window, self.n as f32 * self.step)?;
+        self.n += 1;
+
+        Some(value)
+    }
+}
+
+/// Kind of a local extremum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PeakKind {
+    /// Local maximum.
+    Maximum,
+
+    /// Local minimum.
+    Minimum
+}
+
+/// A local extremum of the window's contents.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Peak<T> {
+    /// Logical index of the extremum.
+    pub index: usize,
+
+    /// Whether the extremum is a maximum or a minimum.
+    pub kind: PeakKind,
+
+    /// Prominence of the extremum.
+    pub prominence: T
+}
+
+/// Iterator over local extrema.
+///
+/// Returned by [`SlidingWindow::peaks`](crate::SlidingWindow::peaks).
+pub struct Peaks<'a, IT> {
+    window: WindowView<'a, IT>,
+    min_prominence: IT,
+    idx: usize
+}
+
+impl<'a, IT> Peaks<'a, IT>
+    where
+        IT: Sample {
+
+    /// Returns the prominence of the element at `idx`, assuming it is a local extremum.
+    ///
+    /// The prominence of a maximum is its height above the higher of the two lowest points between
+    /// it and the nearest higher element (or the end of the window) on either side. Minima are
+    /// handled symmetrically.
+    fn prominence(&self, idx: usize, kind: PeakKind) -> IT {
+        let window = self.window;
+        let value = window[idx];
+
+        // Returns `a` if it is further from the peak than `b`.
+        let deeper = |a: IT, b: IT| match kind {
+            PeakKind::Maximum => if a < b { a } else { b },
+            PeakKind::Minimum => if a > b { a } else { b }
+        };
+        let exceeds = |other: IT| match kind {
+            PeakKind::Maximum => other > value,
+            PeakKind::Minimum => other < value
+        };
+
+        let mut left = value;
+        for i in (0..idx).rev() {
+            if exceeds(window[i]) {
+                break;
+            }
+            left = deeper(left, window[i]);
+        }
+
+        let mut right = value;
+        for i in idx + 1..window.len() {
+            if exceeds(window[i]) {
+                break;
+            }
+            right = deeper(right, window[i]);
+        }
+
+        // The reference level is the shallower of the two sides.
+        let base = if deeper(left, right) == left { right } else { left };
+        match kind {
+            PeakKind::Maximum => value - base,
+            PeakKind::Minimum => base - value
+        }
+    }
+}
+
+impl<'a, IT> Iterator for Peaks<'a, IT>
+    where
+        IT: Sample {
+    type Item = Peak<IT>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.window;
+        while self.idx + 1 < window.len() {
+            let idx = self.idx;
+            self.idx += 1;
+
+            let (prev, current, next) = (window[idx - 1], window[idx], window[idx + 1]);
+            let kind = if current > prev && current > next {
+                PeakKind::Maximum
+            } else if current < prev && current < next {
+                PeakKind::Minimum
+            } else {
+                continue;
+            };
+
+            let prominence = self.prominence(idx, kind);
+            if prominence >= self.min_prominence {
+                return Some(Peak { index: idx, kind, prominence });
+            }
+        }
+
+        None
+    }
+}
+
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT>,
+        IT: Sample {
+
+    /// Returns the dot product of the window's contents and a coefficient array.
+    ///
+    /// The first coefficient is multiplied with the oldest element. If the number of coefficients
+    /// differs from the number of elements, the excess elements or coefficients are ignored.
+    ///
+    /// To use the window as the delay line of an FIR filter, the filter taps must be given in
+    /// reverse order, i.e. the tap that applies to the newest sample comes last.
+    pub fn convolve(&self, coeffs: &[IT]) -> IT {
+        dot_split(self.as_slices(), (coeffs, &[]))
+    }
+
+    /// Returns the dot product of the contents of two windows.
+    ///
+    /// Elements are paired in the order of insertion, starting with the oldest ones. If the windows
+    /// hold a different number of elements, the newest elements of the longer one are ignored.
+    pub fn dot<S2>(&self, other: &SlidingWindowBase<IT, S2>) -> IT
+        where
+            S2: Storage<IT> {
+        dot_split(self.as_slices(), other.as_slices())
+    }
+
+    /// Returns the autocorrelation of the window's contents at the given lag.
+    ///
+    /// This is the sum of the products of each element and the element `lag` positions newer. The
+    /// result is not normalized. If `lag` is not smaller than the number of elements, the result is
+    /// zero.
+    pub fn autocorrelation(&self, lag: usize) -> IT {
+        let contents = self.as_slices();
+        if lag >= self.count() {
+            return IT::ZERO;
+        }
+
+        dot_split(contents, skip(contents, lag))
+    }
+
+    /// Returns an iterator over the local maxima and minima of the window's contents.
+    ///
+    /// An element is a local extremum if it is strictly larger or smaller than both of its
+    /// neighbours, so the oldest and newest elements are never reported. Extrema with a prominence
+    /// smaller than `min_prominence` are skipped.
+    ///
+    /// Computing the prominence of an extremum walks the window in both directions until a more
+    /// extreme element is found, so a full pass is O(N²) in the worst case, e.g. for a long
+    /// monotone trend with many small ripples.
+    pub fn peaks(&self, min_prominence: IT) -> Peaks<'_, IT> {
+        Peaks {
+            window: self.view(..),
+            min_prominence,
+            idx: 1
+        }
+    }
+}
+
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT>,
+        IT: Real {
+
+    /// Returns the value at a fractional position, linearly interpolated between the neighbouring
+    /// elements.
+    ///
+    /// Positions are logical indices, so 0.0 is the oldest element. Returns `None` if `pos` lies
+    /// outside of the stored elements.
+    pub fn interpolate(&self, pos: f32) -> Option<IT> {
+        interpolate(self.view(..), pos)
+    }
+
+    /// Returns an iterator over interpolated values taken `step` elements apart.
+    ///
+    /// The iterator starts at the oldest element and ends at or before the newest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    pub fn resample(&self, step: f32) -> Resample<'_, IT> {
+        assert!(step > 0.0, "Step must be positive");
+
+        Resample {
+            window: self.view(..),
+            step,
+            n: 0
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Peak, PeakKind};
+    use crate::SlidingWindow;
+
+    #[test]
+    fn convolve() {
+        let mut sw: SlidingWindow<f64, 4> = SlidingWindow::new();
+
+        assert_eq!(0.0, sw.convolve(&[1.0, 2.0]));
+
+        sw.insert(1.0);
+        sw.insert(2.0);
+        sw.insert(3.0);
+        assert_eq!(1.0 + 4.0 + 9.0, sw.convolve(&[1.0, 2.0, 3.0, 4.0]));
+        assert_eq!(1.0 + 4.0, sw.convolve(&[1.0, 2.0]));
+
+        sw.insert(4.0);
+        sw.insert(5.0);
+        sw.insert(6.0);
+        assert_eq!(3.0 + 8.0 + 15.0 + 24.0, sw.convolve(&[1.0, 2.0, 3.0, 4.0]));
+        assert_eq!(3.0 + 8.0 + 15.0, sw.convolve(&[1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn dot() {
+        let mut a: SlidingWindow<f64, 4> = SlidingWindow::new();
+        let mut b: SlidingWindow<f64, 3> = SlidingWindow::new();
+
+        assert_eq!(0.0, a.dot(&b));
+
+        for &sample in [1.0, 2.0, 3.0, 4.0, 5.0].iter() {
+            a.insert(sample);
+        }
+        for &sample in [1.0, 2.0, 1.0, 2.0].iter() {
+            b.insert(sample);
+        }
+
+        // [2, 3, 4, 5] . [2, 1, 2]
+        assert_eq!(4.0 + 3.0 + 8.0, a.dot(&b));
+        assert_eq!(a.dot(&b), b.dot(&a));
+    }
+
+    #[test]
+    fn autocorrelation() {
+        let mut sw: SlidingWindow<f64, 4> = SlidingWindow::new();
+
+        for &sample in [9.0, 1.0, 2.0, 3.0, 4.0].iter() {
+            sw.insert(sample);
+        }
+
+        assert_eq!(1.0 + 4.0 + 9.0 + 16.0, sw.autocorrelation(0));
+        assert_eq!(2.0 + 6.0 + 12.0, sw.autocorrelation(1));
+        assert_eq!(4.0, sw.autocorrelation(3));
+        assert_eq!(0.0, sw.autocorrelation(4));
+    }
+
+    #[test]
+    fn interpolate() {
+        let mut sw: SlidingWindow<f32, 4> = SlidingWindow::new();
+
+        assert_eq!(None, sw.interpolate(0.0));
+
+        sw.insert(4.0);
+        assert_eq!(Some(4.0), sw.interpolate(0.0));
+        assert_eq!(None, sw.interpolate(0.5));
+
+        sw.insert(0.0);
+        sw.insert(2.0);
+        sw.insert(4.0);
+        sw.insert(6.0);
+
+        assert_eq!(Some(0.0), sw.interpolate(0.0));
+        assert_eq!(Some(1.5), sw.interpolate(0.75));
+        assert_eq!(Some(5.0), sw.interpolate(2.5));
+        assert_eq!(Some(6.0), sw.interpolate(3.0));
+        assert_eq!(None, sw.interpolate(3.1));
+        assert_eq!(None, sw.interpolate(-0.1));
+    }
+
+    #[test]
+    fn resample() {
+        let mut sw: SlidingWindow<f32, 4> = SlidingWindow::new();
+
+        sw.insert(0.0);
+        sw.insert(3.0);
+        sw.insert(6.0);
+
+        assert!(sw.resample(0.5).eq([0.0, 1.5, 3.0, 4.5, 6.0].iter().copied()));
+        assert!(sw.resample(1.5).eq([0.0, 4.5].iter().copied()));
+    }
+
+    #[test]
+    fn integer_samples() {
+        let mut sw: SlidingWindow<u16, 4> = SlidingWindow::new();
+
+        for &sample in [9, 7, 4, 2].iter() {
+            sw.insert(sample);
+        }
+
+        assert_eq!(9 + 14 + 12 + 8, sw.convolve(&[1, 2, 3, 4]));
+        assert_eq!(63 + 28 + 8, sw.autocorrelation(1));
+
+        sw.clear();
+        for &sample in [9, 2, 5, 1].iter() {
+            sw.insert(sample);
+        }
+
+        let expected = [
+            Peak { index: 1, kind: PeakKind::Minimum, prominence: 3 },
+            Peak { index: 2, kind: PeakKind::Maximum, prominence: 3 }
+        ];
+        assert!(sw.peaks(0).eq(expected.iter().copied()));
+    }
+
+    #[test]
+    fn peaks() {
+        let mut sw: SlidingWindow<f32, 10> = SlidingWindow::new();
+
+        for &sample in [5.0, 1.0, 3.0, 2.0, 2.0, 6.0, 4.0, 4.5, 0.0, 1.0, 1.0].iter() {
+            sw.insert(sample);
+        }
+
+        // [1, 3, 2, 2, 6, 4, 4.5, 0, 1, 1]
+        let expected = [
+            Peak { index: 1, kind: PeakKind::Maximum, prominence: 1.0 },
+            Peak { index: 4, kind: PeakKind::Maximum, prominence: 5.0 },
+            Peak { index: 5, kind: PeakKind::Minimum, prominence: 0.5 },
+            Peak { index: 6, kind: PeakKind::Maximum, prominence: 0.5 },
+            Peak { index: 7, kind: PeakKind::Minimum, prominence: 1.0 }
+        ];
+
+        assert!(sw.peaks(0.0).eq(expected.iter().copied()));
+        assert!(sw.peaks(1.0).eq(expected.iter().copied().filter(|peak| peak.prominence >= 1.0)));
+    }
+}
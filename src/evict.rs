@@ -0,0 +1,132 @@
+//! Handling of elements that are evicted from a window.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::SlidingWindow;
+//!
+//! let mut flushed = Vec::new();
+//! let mut log = SlidingWindow::<u32, 2>::new().with_evict_handler(|record| flushed.push(record));
+//!
+//! log.insert(1);
+//! log.insert(2);
+//! log.insert(3);
+//! drop(log);
+//!
+//! assert_eq!(vec![1], flushed);
+//! ```
+
+use crate::SlidingWindow;
+
+/// Receives the elements that are evicted from a window.
+///
+/// This trait is implemented for every `FnMut(IT)` closure.
+pub trait EvictHandler<IT> {
+    /// Called with the element that has been removed from the window.
+    fn evicted(&mut self, item: IT);
+}
+
+impl<IT, F> EvictHandler<IT> for F
+    where
+        F: FnMut(IT) {
+
+    fn evicted(&mut self, item: IT) {
+        self(item)
+    }
+}
+
+/// A sliding window that passes evicted elements to an [`EvictHandler`].
+///
+/// Returned by [`SlidingWindow::with_evict_handler`]. Elements that are still in the window when
+/// it is dropped are not passed to the handler; use [`clear`](Evicting::clear) to flush them.
+pub struct Evicting<IT, const N: usize, H> {
+    window: SlidingWindow<IT, N>,
+    handler: H
+}
+
+impl<IT, const N: usize, H> Evicting<IT, N, H>
+    where
+        H: EvictHandler<IT> {
+
+    /// Insert an element into the window.
+    ///
+    /// If the window is full, the oldest element is removed and passed to the handler.
+    pub fn insert(&mut self, t: IT) {
+        if let Some(old) = self.window.insert(t) {
+            self.handler.evicted(old);
+        }
+    }
+
+    /// Removes all elements from the window, passing each of them to the handler, oldest first.
+    pub fn clear(&mut self) {
+        while let Some(old) = self.window.pop_oldest() {
+            self.handler.evicted(old);
+        }
+    }
+
+    /// Returns the underlying window.
+    pub fn window(&self) -> &SlidingWindow<IT, N> {
+        &self.window
+    }
+
+    /// Returns the handler.
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Returns the handler.
+    pub fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    /// Splits the object into the underlying window and the handler.
+    pub fn into_parts(self) -> (SlidingWindow<IT, N>, H) {
+        (self.window, self.handler)
+    }
+}
+
+impl<IT, const N: usize> SlidingWindow<IT, N> {
+    /// Wraps the window so that every element overwritten by an insertion is passed to `handler`.
+    pub fn with_evict_handler<H>(self, handler: H) -> Evicting<IT, N, H>
+        where
+            H: EvictHandler<IT> {
+        Evicting {
+            window: self,
+            handler
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Default)]
+    struct Flash {
+        records: Vec<u32>
+    }
+
+    impl EvictHandler<u32> for Flash {
+        fn evicted(&mut self, item: u32) {
+            self.records.push(item);
+        }
+    }
+
+    #[test]
+    fn handler_receives_evicted_elements() {
+        let mut log = SlidingWindow::<u32, 2>::new().with_evict_handler(Flash::default());
+
+        log.insert(1);
+        log.insert(2);
+        assert!(log.handler().records.is_empty());
+
+        log.insert(3);
+        log.insert(4);
+        assert_eq!(&[1, 2], &log.handler().records[..]);
+        assert!(log.window().iter().eq([3, 4].iter()));
+
+        log.clear();
+        assert_eq!(0, log.window().count());
+        assert_eq!(&[1, 2, 3, 4], &log.handler().records[..]);
+    }
+}
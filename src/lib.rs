@@ -6,10 +6,9 @@
 //!
 //! ```rust
 //! use sliding_window::*;
-//! use sliding_window::typenum::consts::*;
 //!
 //! // Create a SlidingWindow with a window size of 4 elements
-//! let mut sw: SlidingWindow<_, U4> = SlidingWindow::new();
+//! let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
 //!
 //! // Insert some data
 //! sw.insert(1);
@@ -25,9 +24,38 @@
 //! ```
 #![cfg_attr(not(test), no_std)]
 
-pub use generic_array::typenum;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod aggregate;
+pub mod analysis;
+#[cfg(feature = "proptest")]
+mod arbitrary;
+#[cfg(feature = "bytemuck")]
+mod bytes;
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+mod convert;
+pub mod dedup;
+pub mod dsp;
+pub mod evict;
+pub mod multi;
+pub mod num;
+#[cfg(feature = "critical-section")]
+pub mod shared;
+pub mod snapshot;
+pub mod spsc;
+pub mod stats;
+pub mod storage;
+pub mod timed;
+pub mod view;
+mod write;
 
 mod wrapping {
+    /// Index arithmetic in the range `0..max`.
+    ///
+    /// The size of array-backed windows is a constant, so once these functions are inlined the
+    /// power-of-two check is resolved at compile time and power-of-two sized windows wrap using a
+    /// single mask.
     pub trait WrappingExt {
         type Rhs;
         type Output;
@@ -38,14 +66,25 @@ mod wrapping {
     impl WrappingExt for usize {
         type Rhs = Self;
         type Output = Self;
+        #[inline(always)]
         fn wrapping_add_limited(self, r: Self::Rhs, max: Self::Rhs) -> Self::Output {
+            if max.is_power_of_two() {
+                // `max` divides the range of `usize`, so overflowing does not change the result.
+                return self.wrapping_add(r) & (max - 1);
+            }
+
             match self.checked_add(r) {
                 Some(v) => v % max,
                 None => (r - (usize::MAX - self)) % max
             }
         }
 
+        #[inline(always)]
         fn wrapping_add1_limited(self, max: Self::Rhs) -> Self::Output {
+            if max.is_power_of_two() {
+                return self.wrapping_add(1) & (max - 1);
+            }
+
             if self == max - 1 { 0 } else { self + 1 }
         }
     }
@@ -63,7 +102,11 @@ mod wrapping {
                 (5, 16, 10, 1),
                 (usize::MAX, usize::MAX, usize::MAX, 0),
                 (usize::MAX, 1, usize::MAX, 1),
-                (usize::MAX - 1, 2, usize::MAX, 1)
+                (usize::MAX - 1, 2, usize::MAX, 1),
+                (5, 6, 8, 3),
+                (7, 1, 8, 0),
+                (usize::MAX, 2, 8, 1),
+                (3, 5, 1, 0)
             ];
 
             for &(lhs, rhs, limit, expectation) in vector.iter() {
@@ -75,7 +118,10 @@ mod wrapping {
         pub fn sanity_check_increment() {
             let vector: &[(usize, usize, usize)] = &[
                 (5, 10, 6),
-                (9, 10, 0)
+                (9, 10, 0),
+                (3, 8, 4),
+                (7, 8, 0),
+                (0, 1, 0)
             ];
 
             for &(lhs, limit, expectation) in vector.iter() {
@@ -85,74 +131,239 @@ mod wrapping {
     }
 }
 
-use generic_array::{GenericArray, ArrayLength, sequence::GenericSequence};
 use wrapping::WrappingExt as _;
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
+use core::ops::Range;
+
+use storage::Storage;
+
+/// Returns the ranges of a ring buffer of `capacity` elements that hold `len` elements starting at
+/// index `start`.
+fn split_ranges(start: usize, len: usize, capacity: usize) -> (Range<usize>, Range<usize>) {
+    let end = start + len;
+    if end <= capacity {
+        (start..end, 0..0)
+    } else {
+        (start..capacity, 0..end - capacity)
+    }
+}
+
+/// Returns `len` elements of `items` starting at index `start` as two slices.
+///
+/// # Safety
+///
+/// The elements must be initialized.
+unsafe fn range_as_slices<IT>(items: &[MaybeUninit<IT>], start: usize, len: usize) -> (&[IT], &[IT]) {
+    let (first, second) = split_ranges(start, len, items.len());
+
+    (slice_assume_init(&items[first]), slice_assume_init(&items[second]))
+}
+
+/// Reinterprets a slice of initialized elements.
+///
+/// # Safety
+///
+/// Every element of `slice` must be initialized.
+unsafe fn slice_assume_init<IT>(slice: &[MaybeUninit<IT>]) -> &[IT] {
+    &*(slice as *const [MaybeUninit<IT>] as *const [IT])
+}
 
-pub trait Size<I>: ArrayLength<MaybeUninit<I>> {}
-impl<T, I> Size<I> for T where T: ArrayLength<MaybeUninit<I>> {}
+/// Reinterprets a mutable slice of initialized elements.
+///
+/// # Safety
+///
+/// Every element of `slice` must be initialized.
+unsafe fn slice_assume_init_mut<IT>(slice: &mut [MaybeUninit<IT>]) -> &mut [IT] {
+    &mut *(slice as *mut [MaybeUninit<IT>] as *mut [IT])
+}
+
+/// A sliding window over the storage `S`.
+///
+/// Sliding windows are queues that overwrite their oldest data when full. This type is usually
+/// used through [`SlidingWindow`], which stores its elements in an array.
+pub struct SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT> {
+    items: S,
+    read_idx: usize,
+    count: usize,
+    _marker: PhantomData<IT>
+}
 
-/// A sliding window.
+/// A sliding window of `N` elements.
 ///
 /// Sliding windows are queues that overwrite their oldest data when full.
-pub struct SlidingWindow<IT, N>
+pub type SlidingWindow<IT, const N: usize> = SlidingWindowBase<IT, [MaybeUninit<IT>; N]>;
+
+/// A sliding window that stores its elements in a buffer borrowed from the caller.
+pub type SlidingWindowRef<'a, IT> = SlidingWindowBase<IT, &'a mut [MaybeUninit<IT>]>;
+
+/// A sliding window whose capacity is chosen at runtime.
+///
+/// The elements are stored in a boxed slice.
+#[cfg(feature = "alloc")]
+pub type DynSlidingWindow<IT> = SlidingWindowBase<IT, alloc::boxed::Box<[MaybeUninit<IT>]>>;
+
+impl<IT, const N: usize> Default for SlidingWindow<IT, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<IT, const N: usize> Clone for SlidingWindow<IT, N>
+    where
+        IT: Clone {
+
+    fn clone(&self) -> Self {
+        let mut window = Self::new();
+        for item in self.iter() {
+            window.insert(item.clone());
+        }
+
+        window
+    }
+}
+
+impl<IT, S> core::fmt::Debug for SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT>,
+        IT: core::fmt::Debug {
+
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_compact(f)
+    }
+}
+
+/// Formats the contents of a window in the order of insertion.
+///
+/// Returned by [`SlidingWindowBase::debug_oldest_first`]. Implements both `Debug` and `Display`,
+/// using the format of [`fmt_compact`](SlidingWindowBase::fmt_compact).
+pub struct Compact<'a, IT, S>
     where
-        N: Size<IT> {
-    items: GenericArray<MaybeUninit<IT>, N>,
-    write_idx: usize,
-    is_full: bool
+        S: Storage<IT> {
+    window: &'a SlidingWindowBase<IT, S>
 }
 
-impl<IT, N> Default for SlidingWindow<IT, N>
+impl<'a, IT, S> core::fmt::Debug for Compact<'a, IT, S>
     where
-        N: Size<IT> {
+        S: Storage<IT>,
+        IT: core::fmt::Debug {
 
-    fn default() -> Self {
-        Self {
-            items: GenericArray::generate(|_| MaybeUninit::uninit()),
-            write_idx: 0,
-            is_full: false
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.window.fmt_compact(f)
+    }
+}
+
+impl<'a, IT, S> core::fmt::Display for Compact<'a, IT, S>
+    where
+        S: Storage<IT>,
+        IT: core::fmt::Debug {
+
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.window.fmt_compact(f)
+    }
+}
+
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT>,
+        IT: core::fmt::Debug {
+
+    /// Writes the contents of the window as `[oldest, ..., newest] (count/capacity)`.
+    ///
+    /// Elements are formatted using their `Debug` implementation and the flags of `f`, so for
+    /// example `{:.2?}` limits the precision of each element.
+    pub fn fmt_compact(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[")?;
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            item.fmt(f)?;
         }
+
+        write!(f, "] ({}/{})", self.count(), self.capacity())
+    }
+
+    /// Returns a wrapper that formats the window using [`fmt_compact`](SlidingWindowBase::fmt_compact).
+    ///
+    /// The wrapper also implements `Display`, so it can be passed to logging macros directly.
+    pub fn debug_oldest_first(&self) -> Compact<'_, IT, S> {
+        Compact { window: self }
+    }
+}
+
+impl<IT, S> Drop for SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT> {
+    fn drop(&mut self) {
+        self.clear();
     }
 }
 
-impl<IT, N> core::ops::Index<usize> for SlidingWindow<IT, N>
+impl<IT, S> core::ops::Index<usize> for SlidingWindowBase<IT, S>
     where
-        N: Size<IT> {
+        S: Storage<IT> {
     type Output = IT;
     fn index(&self, idx: usize) -> &Self::Output {
-        let read_from = if self.is_full {
-            self.write_idx.wrapping_add_limited(idx, N::USIZE)
-        } else {
-            assert!(idx < self.write_idx, "Trying to access uninitialized memory");
-            idx
-        };
+        assert!(idx < self.count, "Trying to access uninitialized memory");
+        let read_from = self.read_idx.wrapping_add_limited(idx, self.capacity());
 
-        unsafe { &*self.items[read_from].as_ptr() }
+        unsafe { &*self.items.as_slice()[read_from].as_ptr() }
     }
 }
 
-/// Read-only iterator that returns elements in the order of insertion.
-pub struct Iter<'a, IT, N>
+impl<'a, IT, S> IntoIterator for &'a SlidingWindowBase<IT, S>
     where
-        N: Size<IT> {
-    window: &'a SlidingWindow<IT, N>,
+        S: Storage<IT> {
+    type Item = &'a IT;
+    type IntoIter = Iter<'a, IT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, IT, S> IntoIterator for &'a mut SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT> {
+    type Item = &'a mut IT;
+    type IntoIter = IterMut<'a, IT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Read-only iterator that returns elements in the order of insertion.
+pub struct Iter<'a, IT> {
+    items: &'a [MaybeUninit<IT>],
     start: usize,
     offset: usize,
     count: usize
 }
 
-impl<'a, IT, N> Iterator for Iter<'a, IT, N>
-    where N:
-        Size<IT> {
+impl<'a, IT> Clone for Iter<'a, IT> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items,
+            start: self.start,
+            offset: self.offset,
+            count: self.count
+        }
+    }
+}
+
+impl<'a, IT> Iterator for Iter<'a, IT> {
     type Item = &'a IT;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.offset < self.count {
-            let read_from = self.start.wrapping_add_limited(self.offset, N::USIZE);
+            let read_from = self.start.wrapping_add_limited(self.offset, self.items.len());
             self.offset += 1;
 
-            Some(unsafe { &*self.window.items[read_from].as_ptr() })
+            Some(unsafe { &*self.items[read_from].as_ptr() })
         } else {
             None
         }
@@ -162,11 +373,35 @@ impl<'a, IT, N> Iterator for Iter<'a, IT, N>
         let remaining = self.count - self.offset;
         (remaining, Some(remaining))
     }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.offset += core::cmp::min(n, self.count - self.offset);
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        let remaining = self.len();
+        self.nth(remaining.checked_sub(1)?)
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+        where
+            F: FnMut(B, Self::Item) -> B {
+        // Process the remaining elements as (at most) two contiguous slices instead of
+        // wrapping the index of every element.
+        let start = self.start.wrapping_add_limited(self.offset, self.items.len());
+        let (first, second) = unsafe { range_as_slices(self.items, start, self.count - self.offset) };
+
+        let acc = first.iter().fold(init, &mut f);
+        second.iter().fold(acc, f)
+    }
 }
 
-impl<'a, IT, N> ExactSizeIterator for Iter<'a, IT, N>
-    where N:
-        Size<IT> {
+impl<'a, IT> ExactSizeIterator for Iter<'a, IT> {
     fn len(&self) -> usize {
         let (lower, upper) = self.size_hint();
         debug_assert_eq!(upper, Some(lower));
@@ -175,37 +410,61 @@ impl<'a, IT, N> ExactSizeIterator for Iter<'a, IT, N>
 }
 
 /// Read-only iterator that does not respect the order of insertion.
-pub struct UnorderedIter<'a, IT, N>
-    where
-        N: Size<IT> {
+pub struct UnorderedIter<'a, IT> {
+    older: core::slice::Iter<'a, IT>,
+    newer: core::slice::Iter<'a, IT>
+}
+
+impl<'a, IT> Iterator for UnorderedIter<'a, IT> {
+    type Item = &'a IT;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.older.next_back().or_else(|| self.newer.next_back())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.older.len() + self.newer.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, IT> ExactSizeIterator for UnorderedIter<'a, IT> {
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
+    }
+}
+
+/// Read-only iterator that returns elements in ascending order.
+pub struct SortedIter<'a, IT, const N: usize> {
     window: &'a SlidingWindow<IT, N>,
-    offset: usize
+    order: [usize; N],
+    offset: usize,
+    count: usize
 }
 
-impl<'a, IT, N> Iterator for UnorderedIter<'a, IT, N>
-    where
-        N: Size<IT> {
+impl<'a, IT, const N: usize> Iterator for SortedIter<'a, IT, N> {
     type Item = &'a IT;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.offset > 0 {
-            self.offset -= 1;
+        if self.offset < self.count {
+            let idx = self.order[self.offset];
+            self.offset += 1;
 
-            Some(unsafe { &*self.window.items[self.offset].as_ptr() })
+            Some(&self.window[idx])
         } else {
             None
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.offset;
+        let remaining = self.count - self.offset;
         (remaining, Some(remaining))
     }
 }
 
-impl<'a, IT, N> ExactSizeIterator for UnorderedIter<'a, IT, N>
-    where N:
-        Size<IT> {
+impl<'a, IT, const N: usize> ExactSizeIterator for SortedIter<'a, IT, N> {
     fn len(&self) -> usize {
         let (lower, upper) = self.size_hint();
         debug_assert_eq!(upper, Some(lower));
@@ -213,138 +472,1186 @@ impl<'a, IT, N> ExactSizeIterator for UnorderedIter<'a, IT, N>
     }
 }
 
-impl<IT, N> SlidingWindow<IT, N>
-    where
-        N: Size<IT> {
+/// Iterator that returns mutable references to the elements in the order of insertion.
+pub struct IterMut<'a, IT> {
+    older: core::slice::IterMut<'a, IT>,
+    newer: core::slice::IterMut<'a, IT>
+}
 
-    /// Returns an empty sliding window object.
-    pub fn new() -> Self {
-        Self::default()
-    }
+impl<'a, IT> Iterator for IterMut<'a, IT> {
+    type Item = &'a mut IT;
 
-    /// Insert an element into the window.
-    ///
-    /// If the window is full, this method will remove and return the oldest element.
-    pub fn insert(&mut self, t: IT) -> Option<IT> {
-        let new: MaybeUninit<IT> = MaybeUninit::new(t);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.older.next().or_else(|| self.newer.next())
+    }
 
-        if !self.is_full {
-            self.items[self.write_idx] = new;
-            if self.write_idx == N::USIZE - 1 {
-                self.write_idx = 0;
-                self.is_full = true;
-            } else {
-                self.write_idx += 1;
-            }
-            None
-        } else {
-            let old = core::mem::replace(&mut self.items[self.write_idx], new);
-            self.write_idx = self.write_idx.wrapping_add1_limited(N::USIZE);
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.older.len() + self.newer.len();
+        (remaining, Some(remaining))
+    }
+}
 
-            Some(unsafe { old.assume_init() })
-        }
+impl<'a, IT> ExactSizeIterator for IterMut<'a, IT> {
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
     }
+}
 
-    /// Removes all elements from the window.
-    pub fn clear(&mut self) {
-        let count = self.count();
-        for elem in &mut self.items[0..count] {
-            unsafe { core::ptr::drop_in_place(elem.as_mut_ptr()); }
-        }
+/// Read-only iterator that returns elements in reverse order of insertion.
+pub struct NewestFirstIter<'a, IT> {
+    items: &'a [MaybeUninit<IT>],
+    start: usize,
+    remaining: usize
+}
 
-        *self = Self::new();
-    }
+impl<'a, IT> Iterator for NewestFirstIter<'a, IT> {
+    type Item = &'a IT;
 
-    /// Returns `true` if the window is full.
-    pub fn is_full(&self) -> bool {
-        self.is_full
-    }
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            let read_from = self.start.wrapping_add_limited(self.remaining, self.items.len());
 
-    /// Returns the number of elements stored in the window.
-    pub fn count(&self) -> usize {
-        if self.is_full {
-            N::USIZE
+            Some(unsafe { &*self.items[read_from].as_ptr() })
         } else {
-            self.write_idx
+            None
         }
     }
 
-    /// Returns an iterator to read from the window.
-    ///
-    /// The iterator starts at the oldest element and ends with the newest.
-    pub fn iter(&self) -> Iter<IT, N> {
-        Iter {
-            window: self,
-            start: if self.is_full() { self.write_idx } else { 0 },
-            offset: 0,
-            count: self.count()
-        }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
+}
 
-    /// Returns an iterator to read from the window.
-    ///
-    /// This iterator starts at the beginning of the internal array instead of the oldest element
-    /// so it does not return the elements in the order of insertion.
-    pub fn iter_unordered(&self) -> UnorderedIter<IT, N> {
-        UnorderedIter {
-            window: self,
-            offset: self.count()
-        }
+impl<'a, IT> ExactSizeIterator for NewestFirstIter<'a, IT> {
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use super::typenum::consts::*;
+/// Iterator over overlapping sub-windows of the window's contents.
+///
+/// Returned by [`SlidingWindow::windows`].
+pub struct Windows<'a, IT> {
+    items: &'a [MaybeUninit<IT>],
+    start: usize,
+    offset: usize,
+    count: usize,
+    size: usize
+}
 
-    #[test]
-    fn basics() {
-        let mut sw: SlidingWindow<_, U4> = SlidingWindow::new();
+impl<'a, IT> Iterator for Windows<'a, IT> {
+    type Item = Iter<'a, IT>;
 
-        sw.insert(1);
-        sw.insert(2);
-        sw.insert(3);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.count {
+            let start = self.start.wrapping_add_limited(self.offset, self.items.len());
+            self.offset += 1;
 
-        assert_eq!(1, sw[0]);
+            Some(Iter {
+                items: self.items,
+                start,
+                offset: 0,
+                count: self.size
+            })
+        } else {
+            None
+        }
+    }
 
-        assert_eq!(3, sw.count());
-        assert_eq!(false, sw.is_full());
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.offset;
+        (remaining, Some(remaining))
+    }
+}
 
-        assert_eq!(None, sw.insert(4));
+impl<'a, IT> ExactSizeIterator for Windows<'a, IT> {
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
+    }
+}
 
-        assert_eq!(1, sw[0]);
-        assert_eq!(4, sw.count());
-        assert_eq!(true, sw.is_full());
+/// Iterator over non-overlapping chunks of the window's contents.
+///
+/// Returned by [`SlidingWindow::chunks`].
+pub struct Chunks<'a, IT> {
+    items: &'a [MaybeUninit<IT>],
+    start: usize,
+    offset: usize,
+    count: usize,
+    size: usize
+}
 
-        assert_eq!(Some(1), sw.insert(5));
+impl<'a, IT> Iterator for Chunks<'a, IT> {
+    type Item = Iter<'a, IT>;
 
-        assert_eq!(2, sw[0]);
-        assert_eq!(4, sw.count());
-        assert_eq!(true, sw.is_full());
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.count {
+            let start = self.start.wrapping_add_limited(self.offset, self.items.len());
+            let count = core::cmp::min(self.size, self.count - self.offset);
+            self.offset += count;
+
+            Some(Iter {
+                items: self.items,
+                start,
+                offset: 0,
+                count
+            })
+        } else {
+            None
+        }
+    }
 
-        sw.clear();
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.offset).div_ceil(self.size);
+        (remaining, Some(remaining))
+    }
+}
 
-        assert_eq!(0, sw.count());
-        assert_eq!(false, sw.is_full());
+impl<'a, IT> ExactSizeIterator for Chunks<'a, IT> {
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
     }
+}
 
-    #[test]
-    fn iter() {
-        let mut sw: SlidingWindow<_, U4> = SlidingWindow::new();
+/// Iterator that returns every `step`-th element, starting with the oldest.
+///
+/// Returned by [`SlidingWindow::iter_step_by`].
+pub struct StepBy<'a, IT> {
+    items: &'a [MaybeUninit<IT>],
+    next_idx: usize,
+    remaining: usize,
+    step: usize
+}
 
-        sw.insert(1);
-        sw.insert(2);
-        sw.insert(3);
-        sw.insert(4);
-        sw.insert(5);
-        sw.insert(6);
+impl<'a, IT> Iterator for StepBy<'a, IT> {
+    type Item = &'a IT;
 
-        assert_eq!(&3, sw.iter().next().unwrap()); // first element is the oldest
-        assert_eq!(18, sw.iter().sum());
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining > 0 {
+            let read_from = self.next_idx;
+            self.next_idx = self.next_idx.wrapping_add_limited(self.step, self.items.len());
+            self.remaining -= 1;
 
-        let mut ordered = sw.iter();
-        let mut unordered = sw.iter_unordered();
+            Some(unsafe { &*self.items[read_from].as_ptr() })
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, IT> ExactSizeIterator for StepBy<'a, IT> {
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
+    }
+}
+
+/// Iterator over pairs of elements of two windows, in the order of insertion.
+///
+/// Returned by [`SlidingWindow::zip`].
+pub struct Zip<'a, A, B> {
+    a: (&'a [A], &'a [A]),
+    b: (&'a [B], &'a [B])
+}
+
+impl<'a, A, B> Zip<'a, A, B> {
+    /// Moves to the second part of the sequences whose first part is exhausted.
+    fn normalize(&mut self) {
+        if self.a.0.is_empty() {
+            self.a = (self.a.1, &[]);
+        }
+        if self.b.0.is_empty() {
+            self.b = (self.b.1, &[]);
+        }
+    }
+}
+
+impl<'a, A, B> Iterator for Zip<'a, A, B> {
+    type Item = (&'a A, &'a B);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.normalize();
+
+        let (a, a_rest) = self.a.0.split_first()?;
+        let (b, b_rest) = self.b.0.split_first()?;
+        self.a.0 = a_rest;
+        self.b.0 = b_rest;
+
+        Some((a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = core::cmp::min(self.a.0.len() + self.a.1.len(), self.b.0.len() + self.b.1.len());
+        (remaining, Some(remaining))
+    }
+
+    fn fold<Acc, F>(mut self, init: Acc, mut f: F) -> Acc
+        where
+            F: FnMut(Acc, Self::Item) -> Acc {
+        // Process the pairs in runs where both sides are contiguous.
+        let mut acc = init;
+        loop {
+            self.normalize();
+
+            let len = core::cmp::min(self.a.0.len(), self.b.0.len());
+            if len == 0 {
+                return acc;
+            }
+
+            let (a, a_rest) = self.a.0.split_at(len);
+            let (b, b_rest) = self.b.0.split_at(len);
+            acc = a.iter().zip(b.iter()).fold(acc, &mut f);
+            self.a.0 = a_rest;
+            self.b.0 = b_rest;
+        }
+    }
+}
+
+impl<'a, A, B> ExactSizeIterator for Zip<'a, A, B> {
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
+    }
+}
+
+/// Iterator over the differences between consecutive elements.
+///
+/// Returned by [`SlidingWindow::deltas`].
+pub struct Deltas<'a, IT> {
+    iter: Iter<'a, IT>,
+    prev: Option<IT>
+}
+
+impl<'a, IT> Iterator for Deltas<'a, IT>
+    where
+        IT: Copy + core::ops::Sub {
+    type Item = IT::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let prev = self.prev?;
+        let next = *self.iter.next()?;
+        self.prev = Some(next);
+
+        Some(next - prev)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.prev.is_some() { self.iter.len() } else { 0 };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, IT> ExactSizeIterator for Deltas<'a, IT>
+    where
+        IT: Copy + core::ops::Sub {
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
+    }
+}
+
+/// Iterator over pairs of consecutive elements.
+///
+/// Returned by [`SlidingWindow::iter_pairs`].
+pub struct Pairs<'a, IT> {
+    iter: Iter<'a, IT>,
+    prev: Option<&'a IT>
+}
+
+impl<'a, IT> Iterator for Pairs<'a, IT> {
+    type Item = (&'a IT, &'a IT);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let prev = self.prev?;
+        let next = self.iter.next()?;
+        self.prev = Some(next);
+
+        Some((prev, next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.prev.is_some() { self.iter.len() } else { 0 };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, IT> ExactSizeIterator for Pairs<'a, IT> {
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
+    }
+}
+
+/// How [`SlidingWindow::insert_missing`] fills the slots of samples that were not received.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fill<T> {
+    /// Repeat the newest element.
+    Repeat,
+
+    /// Insert clones of the given value.
+    Value(T)
+}
+
+impl<IT, const N: usize> SlidingWindow<IT, N> {
+    /// Returns an empty sliding window object.
+    ///
+    /// This function can be used to initialize a `static` window.
+    ///
+    /// Windows need at least one slot to work, so a capacity of zero is rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use sliding_window::SlidingWindow;
+    ///
+    /// let window: SlidingWindow<u32, 0> = SlidingWindow::new();
+    /// ```
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity must be non-zero") };
+
+        Self {
+            items: [const { MaybeUninit::uninit() }; N],
+            read_idx: 0,
+            count: 0,
+            _marker: PhantomData
+        }
+    }
+}
+
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT> {
+
+    /// Returns the maximum number of elements the window can hold.
+    pub fn capacity(&self) -> usize {
+        self.items.as_slice().len()
+    }
+
+    /// Insert an element into the window.
+    ///
+    /// If the window is full, this method will remove and return the oldest element.
+    pub fn insert(&mut self, t: IT) -> Option<IT> {
+        let new: MaybeUninit<IT> = MaybeUninit::new(t);
+
+        if !self.is_full() {
+            let write_idx = self.read_idx.wrapping_add_limited(self.count, self.capacity());
+            self.items.as_mut_slice()[write_idx] = new;
+            self.count += 1;
+            None
+        } else {
+            let old = core::mem::replace(&mut self.items.as_mut_slice()[self.read_idx], new);
+            self.read_idx = self.read_idx.wrapping_add1_limited(self.capacity());
+
+            Some(unsafe { old.assume_init() })
+        }
+    }
+
+    /// Insert an element into the window, overwriting the oldest element if the window is full.
+    ///
+    /// This is the same as [`insert`](SlidingWindow::insert).
+    pub fn insert_overwrite(&mut self, t: IT) -> Option<IT> {
+        self.insert(t)
+    }
+
+    /// Insert an element into the window, unless the window is full.
+    ///
+    /// If the window is full, the element is not inserted and is returned as an error.
+    pub fn try_insert(&mut self, t: IT) -> Result<(), IT> {
+        if self.is_full() {
+            Err(t)
+        } else {
+            self.insert(t);
+            Ok(())
+        }
+    }
+
+    /// Removes and returns the oldest element, or `None` if the window is empty.
+    pub(crate) fn pop_oldest(&mut self) -> Option<IT> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let old = core::mem::replace(&mut self.items.as_mut_slice()[self.read_idx], MaybeUninit::uninit());
+        self.read_idx = self.read_idx.wrapping_add1_limited(self.capacity());
+        self.count -= 1;
+
+        Some(unsafe { old.assume_init() })
+    }
+
+    /// Removes and returns the newest element, or `None` if the window is empty.
+    pub(crate) fn pop_newest(&mut self) -> Option<IT> {
+        if self.count == 0 {
+            return None;
+        }
+
+        self.count -= 1;
+        let idx = self.read_idx.wrapping_add_limited(self.count, self.capacity());
+        let old = core::mem::replace(&mut self.items.as_mut_slice()[idx], MaybeUninit::uninit());
+
+        Some(unsafe { old.assume_init() })
+    }
+
+    /// Replaces the element at logical index `idx` with `value`, and returns the old element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not smaller than [`count`](SlidingWindowBase::count).
+    pub fn replace(&mut self, idx: usize, value: IT) -> IT {
+        assert!(idx < self.count, "Trying to access uninitialized memory");
+
+        core::mem::replace(unsafe { self.get_unchecked_mut(idx) }, value)
+    }
+
+    /// Swaps the elements at logical indices `i` and `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is not smaller than [`count`](SlidingWindowBase::count).
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.count && j < self.count, "Trying to access uninitialized memory");
+
+        let capacity = self.capacity();
+        let i = self.read_idx.wrapping_add_limited(i, capacity);
+        let j = self.read_idx.wrapping_add_limited(j, capacity);
+        self.items.as_mut_slice().swap(i, j);
+    }
+
+    /// Removes and returns the element at logical index `idx`, replacing it with the newest element.
+    ///
+    /// This does not preserve the order of insertion, but is O(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not smaller than [`count`](SlidingWindowBase::count).
+    pub fn swap_remove(&mut self, idx: usize) -> IT {
+        assert!(idx < self.count, "Trying to access uninitialized memory");
+
+        self.swap(idx, self.count - 1);
+        self.pop_newest().unwrap()
+    }
+
+    /// Replaces the contents of the window with clones of `value`, one for each slot.
+    ///
+    /// The window is full afterwards.
+    pub fn fill(&mut self, value: IT)
+        where
+            IT: Clone {
+        self.fill_with(|| value.clone());
+    }
+
+    /// Replaces the contents of the window with elements returned by `f`, one for each slot.
+    ///
+    /// The generated elements are inserted in the order `f` returns them. The window is full
+    /// afterwards.
+    pub fn fill_with<F>(&mut self, mut f: F)
+        where
+            F: FnMut() -> IT {
+        self.clear();
+        for item in self.items.as_mut_slice().iter_mut() {
+            *item = MaybeUninit::new(f());
+            self.count += 1;
+        }
+    }
+
+    /// Advances the window by `n` samples that were not received, filling their slots according
+    /// to `fill`.
+    ///
+    /// Afterwards, the elements are in the same position as if `n` samples had been inserted, so
+    /// statistics over the window stay aligned in time. If the window is empty, [`Fill::Repeat`]
+    /// has nothing to repeat and the window is left unchanged.
+    pub fn insert_missing(&mut self, n: usize, fill: Fill<IT>)
+        where
+            IT: Clone {
+        let value = match fill {
+            Fill::Repeat => match self.nth_from_newest(0) {
+                Some(newest) => newest.clone(),
+                None => return
+            },
+            Fill::Value(value) => value
+        };
+
+        // Only the last `capacity` insertions can be observed.
+        for _ in 0..core::cmp::min(n, self.capacity()) {
+            self.insert(value.clone());
+        }
+    }
+
+    /// Removes the `k` oldest elements from the window.
+    ///
+    /// If the window holds fewer than `k` elements, all of them are removed.
+    pub fn truncate_oldest(&mut self, k: usize) {
+        for _ in 0..k {
+            if self.pop_oldest().is_none() {
+                break;
+            }
+        }
+    }
+
+    /// Retains only the elements for which `f` returns `true`.
+    ///
+    /// The remaining elements keep their order of insertion.
+    pub fn retain<F>(&mut self, mut f: F)
+        where
+            F: FnMut(&IT) -> bool {
+        // Retained elements are swapped in front of the rejected ones, and the window is only
+        // shortened at the end. If `f` panics, the window still holds every element, with the
+        // retained ones first and in their order of insertion.
+        let capacity = self.capacity();
+        let mut kept = 0;
+        for cur in 0..self.count {
+            if f(&self[cur]) {
+                if kept != cur {
+                    let kept_idx = self.read_idx.wrapping_add_limited(kept, capacity);
+                    let cur_idx = self.read_idx.wrapping_add_limited(cur, capacity);
+                    self.items.as_mut_slice().swap(kept_idx, cur_idx);
+                }
+                kept += 1;
+            }
+        }
+
+        while self.count > kept {
+            self.count -= 1;
+            let idx = self.read_idx.wrapping_add_limited(self.count, capacity);
+            unsafe { core::ptr::drop_in_place(self.items.as_mut_slice()[idx].as_mut_ptr()); }
+        }
+    }
+
+    /// Removes all elements from the window.
+    pub fn clear(&mut self) {
+        let (older, newer) = self.as_mut_slices();
+        unsafe {
+            core::ptr::drop_in_place(older);
+            core::ptr::drop_in_place(newer);
+        }
+
+        self.read_idx = 0;
+        self.count = 0;
+    }
+
+    /// Rearranges the internal storage so that the elements are contiguous, and returns them as a
+    /// slice in the order of insertion.
+    pub fn make_contiguous(&mut self) -> &mut [IT] {
+        let read_idx = self.read_idx;
+        self.items.as_mut_slice().rotate_left(read_idx);
+        self.read_idx = 0;
+
+        let count = self.count;
+        unsafe { slice_assume_init_mut(&mut self.items.as_mut_slice()[..count]) }
+    }
+
+    /// Rotates the elements `k` places to the left, so that the element at index `k` becomes the
+    /// first one.
+    ///
+    /// This is O(1) when the window is full, as only the position of the first element changes.
+    /// Otherwise the elements are moved.
+    ///
+    /// The element at index 0 is always the next one to be evicted, so rotating a full window also
+    /// changes which element [`insert`](SlidingWindowBase::insert) removes. After `rotate_left(k)`,
+    /// the `count - k` elements that used to be the newest are evicted first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than [`count`](SlidingWindowBase::count).
+    pub fn rotate_left(&mut self, k: usize) {
+        assert!(k <= self.count, "Rotation out of bounds");
+
+        if self.is_full() {
+            self.read_idx = self.read_idx.wrapping_add_limited(k, self.capacity());
+        } else {
+            self.make_contiguous().rotate_left(k);
+        }
+    }
+
+    /// Rotates the elements `k` places to the right, so that the element at index `count - k`
+    /// becomes the first one.
+    ///
+    /// This is O(1) when the window is full, as only the position of the first element changes.
+    /// Otherwise the elements are moved.
+    ///
+    /// The element at index 0 is always the next one to be evicted, so rotating a full window also
+    /// changes which element [`insert`](SlidingWindowBase::insert) removes. After `rotate_right(k)`,
+    /// the `k` elements that used to be the newest are evicted first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than [`count`](SlidingWindowBase::count).
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.count, "Rotation out of bounds");
+
+        self.rotate_left(self.count - k);
+    }
+
+    /// Returns the index of the oldest element in the internal array.
+    fn oldest_idx(&self) -> usize {
+        self.read_idx
+    }
+
+    /// Returns the ranges of the internal array that hold the contents of the window, in the
+    /// order of insertion.
+    fn as_slice_ranges(&self) -> (Range<usize>, Range<usize>) {
+        split_ranges(self.read_idx, self.count, self.capacity())
+    }
+
+    /// Returns the contents of the window as two slices, in the order of insertion.
+    pub(crate) fn as_slices(&self) -> (&[IT], &[IT]) {
+        unsafe { range_as_slices(self.items.as_slice(), self.read_idx, self.count) }
+    }
+
+    /// Returns the contents of the window as two mutable slices, in the order of insertion.
+    pub(crate) fn as_mut_slices(&mut self) -> (&mut [IT], &mut [IT]) {
+        let (older, newer) = self.as_slice_ranges();
+        let (head, tail) = self.items.as_mut_slice().split_at_mut(older.start);
+
+        unsafe { (slice_assume_init_mut(&mut tail[..older.len()]), slice_assume_init_mut(&mut head[newer])) }
+    }
+
+    /// Returns a reference to the element at logical index `idx`, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `idx` must be smaller than [`count`](SlidingWindow::count).
+    pub unsafe fn get_unchecked(&self, idx: usize) -> &IT {
+        let read_from = self.read_idx.wrapping_add_limited(idx, self.capacity());
+        &*self.items.as_slice().get_unchecked(read_from).as_ptr()
+    }
+
+    /// Returns a mutable reference to the element at logical index `idx`, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `idx` must be smaller than [`count`](SlidingWindow::count).
+    pub unsafe fn get_unchecked_mut(&mut self, idx: usize) -> &mut IT {
+        let read_from = self.read_idx.wrapping_add_limited(idx, self.capacity());
+        &mut *self.items.as_mut_slice().get_unchecked_mut(read_from).as_mut_ptr()
+    }
+
+    /// Returns the `k`-th newest element, where `k = 0` is the newest.
+    ///
+    /// Returns `None` if the window holds `k` or fewer elements, e.g. while it is still filling.
+    pub fn nth_from_newest(&self, k: usize) -> Option<&IT> {
+        if k < self.count {
+            Some(unsafe { self.get_unchecked(self.count - 1 - k) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the window is full.
+    pub fn is_full(&self) -> bool {
+        self.count == self.capacity()
+    }
+
+    /// Returns the number of elements stored in the window.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns an iterator to read from the window.
+    ///
+    /// The iterator starts at the oldest element and ends with the newest.
+    pub fn iter(&self) -> Iter<'_, IT> {
+        Iter {
+            items: self.items.as_slice(),
+            start: self.oldest_idx(),
+            offset: 0,
+            count: self.count()
+        }
+    }
+
+    /// Returns an iterator over the `n` newest elements.
+    ///
+    /// The iterator starts at the oldest of these elements and ends with the newest. If the window
+    /// holds fewer than `n` elements, all of them are returned.
+    pub fn iter_latest(&self, n: usize) -> Iter<'_, IT> {
+        let count = self.count();
+
+        Iter {
+            items: self.items.as_slice(),
+            start: self.oldest_idx(),
+            offset: count - core::cmp::min(n, count),
+            count
+        }
+    }
+
+    /// Returns an iterator over every `step`-th element.
+    ///
+    /// The iterator starts at the oldest element. Unlike [`Iterator::step_by`], skipped elements
+    /// are not visited.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0.
+    pub fn iter_step_by(&self, step: usize) -> StepBy<'_, IT> {
+        assert_ne!(step, 0, "Step must be non-zero");
+
+        StepBy {
+            items: self.items.as_slice(),
+            next_idx: self.oldest_idx(),
+            remaining: self.count().div_ceil(step),
+            step
+        }
+    }
+
+    /// Returns an iterator over pairs of elements of this and another window.
+    ///
+    /// Elements are paired in the order of insertion, starting with the oldest ones. If the windows
+    /// hold a different number of elements, the newest elements of the longer one are ignored.
+    pub fn zip<'a, U, S2>(&'a self, other: &'a SlidingWindowBase<U, S2>) -> Zip<'a, IT, U>
+        where
+            S2: Storage<U> {
+        Zip {
+            a: self.as_slices(),
+            b: other.as_slices()
+        }
+    }
+
+    /// Returns an iterator that allows modifying the elements of the window.
+    ///
+    /// The iterator starts at the oldest element and ends with the newest.
+    pub fn iter_mut(&mut self) -> IterMut<'_, IT> {
+        let (older, newer) = self.as_mut_slices();
+
+        IterMut {
+            older: older.iter_mut(),
+            newer: newer.iter_mut()
+        }
+    }
+
+    /// Returns an iterator to read from the window, in reverse order.
+    ///
+    /// The iterator starts at the newest element and ends with the oldest.
+    pub fn iter_newest_first(&self) -> NewestFirstIter<'_, IT> {
+        NewestFirstIter {
+            items: self.items.as_slice(),
+            start: self.oldest_idx(),
+            remaining: self.count()
+        }
+    }
+
+    /// Returns an iterator over all overlapping sub-windows of `size` elements.
+    ///
+    /// Sub-windows are returned in the order of insertion, each one being an iterator that starts
+    /// at its oldest element. If the window holds fewer than `size` elements, the iterator is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn windows(&self, size: usize) -> Windows<'_, IT> {
+        assert_ne!(size, 0, "Window size must be non-zero");
+
+        let count = self.count();
+        Windows {
+            items: self.items.as_slice(),
+            start: self.oldest_idx(),
+            offset: 0,
+            count: if count < size { 0 } else { count - size + 1 },
+            size
+        }
+    }
+
+    /// Returns an iterator over non-overlapping chunks of `size` elements.
+    ///
+    /// Chunks are returned in the order of insertion. If the number of elements is not divisible
+    /// by `size`, the last chunk will be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks(&self, size: usize) -> Chunks<'_, IT> {
+        assert_ne!(size, 0, "Chunk size must be non-zero");
+
+        Chunks {
+            items: self.items.as_slice(),
+            start: self.oldest_idx(),
+            offset: 0,
+            count: self.count(),
+            size
+        }
+    }
+
+    /// Folds the contents of the window as contiguous slices, in the order of insertion.
+    ///
+    /// `f` is called with the accumulator and a slice, and returns the new accumulator. Depending
+    /// on where the oldest element is stored, `f` is called once or twice, and not at all if the
+    /// window is empty. This allows passing the contents to slice based algorithms without
+    /// copying them.
+    ///
+    /// If `f` returns an error, the remaining slices are skipped and the error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sliding_window::SlidingWindow;
+    ///
+    /// let mut window: SlidingWindow<u8, 4> = SlidingWindow::new();
+    /// for byte in 1..=6 {
+    ///     window.insert(byte);
+    /// }
+    ///
+    /// let checksum = window.process_segments(0u8, |sum, segment| {
+    ///     Ok::<_, ()>(segment.iter().fold(sum, |sum, &byte| sum ^ byte))
+    /// });
+    /// assert_eq!(Ok(3 ^ 4 ^ 5 ^ 6), checksum);
+    /// ```
+    pub fn process_segments<B, E, F>(&self, init: B, mut f: F) -> Result<B, E>
+        where
+            F: FnMut(B, &[IT]) -> Result<B, E> {
+        let (older, newer) = self.as_slices();
+        [older, newer].iter()
+            .filter(|segment| !segment.is_empty())
+            .try_fold(init, |acc, segment| f(acc, segment))
+    }
+
+    /// Returns an iterator over pairs of consecutive elements.
+    ///
+    /// Each pair holds an element and the next newer one, in the order of insertion. A window of
+    /// `n` elements yields `n - 1` pairs.
+    pub fn iter_pairs(&self) -> Pairs<'_, IT> {
+        let mut iter = self.iter();
+        let prev = iter.next();
+
+        Pairs { iter, prev }
+    }
+
+    /// Returns an iterator to read from the window.
+    ///
+    /// This iterator starts at the beginning of the internal array instead of the oldest element
+    /// so it does not return the elements in the order of insertion.
+    pub fn iter_unordered(&self) -> UnorderedIter<'_, IT> {
+        let (older, newer) = self.as_slices();
+
+        UnorderedIter {
+            older: older.iter(),
+            newer: newer.iter()
+        }
+    }
+
+    /// Binary searches the window with a comparator function.
+    ///
+    /// The window is expected to be sorted in the order of insertion. The comparator should return
+    /// the ordering of the element relative to the searched value. See [`slice::binary_search_by`]
+    /// for details of the returned value.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+        where
+            F: FnMut(&IT) -> core::cmp::Ordering {
+        let (older, newer) = self.as_slices();
+
+        match older.last() {
+            Some(last) if f(last) != core::cmp::Ordering::Less => older.binary_search_by(f),
+            _ => newer.binary_search_by(f)
+                .map(|idx| idx + older.len())
+                .map_err(|idx| idx + older.len())
+        }
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`.
+    ///
+    /// The window is expected to be partitioned in the order of insertion, so that `pred` returns
+    /// `true` for every element before the returned index and `false` for every element after it.
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+        where
+            P: FnMut(&IT) -> bool {
+        let (older, newer) = self.as_slices();
+
+        match older.last() {
+            Some(last) if !pred(last) => older.partition_point(pred),
+            _ => older.len() + newer.partition_point(pred)
+        }
+    }
+}
+
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT>,
+        IT: Ord {
+
+    /// Binary searches the window for `key`.
+    ///
+    /// The window is expected to be sorted in the order of insertion. See [`slice::binary_search`]
+    /// for details of the returned value.
+    pub fn binary_search(&self, key: &IT) -> Result<usize, usize> {
+        self.binary_search_by(|x| x.cmp(key))
+    }
+}
+
+impl<IT, const N: usize> SlidingWindow<IT, N>
+    where
+        IT: PartialOrd {
+
+    /// Returns an iterator that returns the elements in ascending order.
+    ///
+    /// The order is computed up front by sorting an array of `N` indices on the stack. Elements
+    /// that can not be compared (e.g. `NaN`) are returned after every other element.
+    pub fn iter_sorted(&self) -> SortedIter<'_, IT, N> {
+        let mut order = self.element_indices();
+        order[..self.count].sort_unstable_by(|&a, &b| compare(&self[a], &self[b]));
+
+        SortedIter {
+            window: self,
+            order,
+            offset: 0,
+            count: self.count
+        }
+    }
+
+    /// Returns the `k`-th smallest element, where `k = 0` is the minimum.
+    ///
+    /// Returns `None` if `k` is not less than the number of elements in the window. Elements that
+    /// can not be compared (e.g. `NaN`) are ordered after every other element.
+    pub fn nth_smallest(&self, k: usize) -> Option<&IT> {
+        if k >= self.count {
+            return None;
+        }
+
+        let mut order = self.element_indices();
+        let (_, &mut nth, _) = order[..self.count]
+            .select_nth_unstable_by(k, |&a, &b| compare(&self[a], &self[b]));
+
+        Some(&self[nth])
+    }
+
+    fn element_indices(&self) -> [usize; N] {
+        let mut order = [0; N];
+        for (i, idx) in order.iter_mut().enumerate() {
+            *idx = i;
+        }
+        order
+    }
+}
+
+/// Orders elements that can not be compared with themselves (e.g. `NaN`) after every other element.
+fn compare<IT: PartialOrd>(a: &IT, b: &IT) -> core::cmp::Ordering {
+    a.partial_cmp(b).unwrap_or_else(|| {
+        let a_incomparable = a.partial_cmp(a).is_none();
+        let b_incomparable = b.partial_cmp(b).is_none();
+
+        a_incomparable.cmp(&b_incomparable)
+    })
+}
+
+impl<T, S> SlidingWindowBase<Option<T>, S>
+    where
+        S: Storage<Option<T>> {
+
+    /// Advances the window by `n` samples that were not received, recording them as `None`.
+    ///
+    /// Unlike [`insert_missing`](SlidingWindowBase::insert_missing), this does not require the
+    /// elements to be `Clone`.
+    pub fn mark_missing(&mut self, n: usize) {
+        for _ in 0..core::cmp::min(n, self.capacity()) {
+            self.insert(None);
+        }
+    }
+}
+
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT>,
+        IT: Copy + core::ops::Sub {
+
+    /// Returns an iterator over the differences between consecutive elements.
+    ///
+    /// For each pair of neighbouring elements, the iterator returns `newer - older`, in the order
+    /// of insertion. A window of `n` elements yields `n - 1` differences.
+    pub fn deltas(&self) -> Deltas<'_, IT> {
+        let mut iter = self.iter();
+        let prev = iter.next().copied();
+
+        Deltas { iter, prev }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn basics() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        sw.insert(1);
+        sw.insert(2);
+        sw.insert(3);
+
+        assert_eq!(1, sw[0]);
+
+        assert_eq!(3, sw.count());
+        assert!(!sw.is_full());
+
+        assert_eq!(None, sw.insert(4));
+
+        assert_eq!(1, sw[0]);
+        assert_eq!(4, sw.count());
+        assert!(sw.is_full());
+
+        assert_eq!(Some(1), sw.insert(5));
+
+        assert_eq!(2, sw[0]);
+        assert_eq!(4, sw.count());
+        assert!(sw.is_full());
+
+        sw.clear();
+
+        assert_eq!(0, sw.count());
+        assert!(!sw.is_full());
+    }
+
+    #[test]
+    fn try_insert() {
+        let mut sw: SlidingWindow<_, 2> = SlidingWindow::new();
+
+        assert_eq!(Ok(()), sw.try_insert(1));
+        assert_eq!(Ok(()), sw.try_insert(2));
+        assert_eq!(Err(3), sw.try_insert(3));
+        assert!(sw.iter().eq([1, 2].iter()));
+
+        assert_eq!(Some(1), sw.insert_overwrite(3));
+        assert!(sw.iter().eq([2, 3].iter()));
+    }
+
+    #[test]
+    fn get_unchecked() {
+        let mut sw: SlidingWindow<_, 3> = SlidingWindow::new();
+
+        for i in 1..=4 {
+            sw.insert(i);
+        }
+
+        unsafe {
+            assert_eq!(2, *sw.get_unchecked(0));
+            assert_eq!(4, *sw.get_unchecked(2));
+
+            *sw.get_unchecked_mut(1) = 10;
+        }
+        assert!(sw.iter().eq([2, 10, 4].iter()));
+    }
+
+    #[test]
+    fn fill() {
+        let mut sw: SlidingWindow<_, 3> = SlidingWindow::new();
+
+        sw.insert(1);
+        sw.fill(5);
+        assert!(sw.is_full());
+        assert!(sw.iter().eq([5, 5, 5].iter()));
+
+        let mut next = 0;
+        sw.fill_with(|| {
+            next += 1;
+            next
+        });
+        assert!(sw.iter().eq([1, 2, 3].iter()));
+        assert_eq!(Some(1), sw.insert(4));
+    }
+
+    #[test]
+    fn truncate_oldest() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        for i in 1..=6 {
+            sw.insert(i);
+        }
+
+        sw.truncate_oldest(3);
+        assert!(sw.iter().eq([6].iter()));
+
+        sw.insert(7);
+        assert!(sw.iter().eq([6, 7].iter()));
+
+        sw.truncate_oldest(5);
+        assert_eq!(0, sw.count());
+    }
+
+    #[test]
+    fn retain() {
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+
+        for i in 1..=8 {
+            sw.insert(i);
+        }
+
+        sw.retain(|&item| item % 2 == 0);
+        assert!(sw.iter().eq([4, 6, 8].iter()));
+
+        sw.insert(9);
+        sw.insert(10);
+        assert!(sw.is_full());
+        assert!(sw.iter().eq([4, 6, 8, 9, 10].iter()));
+    }
+
+    #[test]
+    fn retain_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+
+        for i in 1..=7 {
+            sw.insert(i);
+        }
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            sw.retain(|&item| {
+                assert_ne!(6, item);
+                item % 2 == 0
+            })
+        }));
+        assert!(result.is_err());
+
+        assert_eq!(5, sw.count());
+        assert_eq!(4, sw[0]);
+
+        let mut items = sw.iter().copied().collect::<Vec<_>>();
+        items.sort_unstable();
+        assert_eq!([3, 4, 5, 6, 7], items[..]);
+    }
+
+    #[test]
+    fn retain_drops_rejected() {
+        use std::rc::Rc;
+
+        let item = Rc::new(());
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        for i in 0..6 {
+            sw.insert((i, item.clone()));
+        }
+
+        sw.retain(|(i, _)| i % 2 == 1);
+        assert!(sw.iter().map(|(i, _)| *i).eq([3, 5].iter().copied()));
+        assert_eq!(3, Rc::strong_count(&item));
+    }
+
+    #[test]
+    fn iter() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        sw.insert(1);
+        sw.insert(2);
+        sw.insert(3);
+        sw.insert(4);
+        sw.insert(5);
+        sw.insert(6);
+
+        assert_eq!(&3, sw.iter().next().unwrap()); // first element is the oldest
+        assert_eq!(18, sw.iter().sum::<i32>());
+
+        let mut ordered = sw.iter();
+        let mut unordered = sw.iter_unordered();
 
         assert_eq!(4, ordered.len());
         assert_eq!(4, unordered.len());
@@ -359,29 +1666,522 @@ mod test {
         assert_eq!(2, unordered.len());
     }
 
+    #[test]
+    fn iter_overrides() {
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+
+        assert_eq!(None, sw.iter().last());
+        assert_eq!(None, sw.iter().nth(1));
+        assert_eq!(0, sw.iter().fold(0, |acc, &x| acc * 2 + x));
+
+        for i in 1..=8 {
+            sw.insert(i);
+        }
+
+        // [4, 5, 6, 7, 8], wrapped in the internal array
+        let mut iter = sw.iter();
+        assert_eq!(Some(&5), iter.nth(1));
+        assert_eq!(Some(&8), iter.nth(2));
+        assert_eq!(None, iter.nth(1));
+
+        let mut iter = sw.iter();
+        iter.next();
+        assert_eq!(Some(&8), iter.clone().last());
+        assert_eq!(4, iter.clone().count());
+        assert_eq!(None, iter.clone().nth(4));
+
+        let mut folded = Vec::new();
+        iter.fold((), |_, &x| folded.push(x));
+        assert_eq!(vec![5, 6, 7, 8], folded);
+        assert_eq!(30, sw.iter().sum::<i32>());
+        assert_eq!(45678, sw.iter().fold(0, |acc, &x| acc * 10 + x));
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        for i in 1..=6 {
+            sw.insert(i);
+        }
+
+        for x in &mut sw {
+            *x *= 10;
+        }
+
+        let mut collected = Vec::new();
+        for &x in &sw {
+            collected.push(x);
+        }
+        assert_eq!(vec![30, 40, 50, 60], collected);
+        assert_eq!(4, sw.iter_mut().len());
+
+        fn total<'a>(items: impl IntoIterator<Item = &'a u32>) -> u32 {
+            items.into_iter().sum()
+        }
+        assert_eq!(180, total(&sw));
+    }
+
+    #[test]
+    fn sorted() {
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+
+        assert_eq!(0, sw.iter_sorted().len());
+        assert_eq!(None, sw.nth_smallest(0));
+
+        for &x in &[9, 4, 7, 1, 8, 3, 6] {
+            sw.insert(x);
+        }
+
+        // [7, 1, 8, 3, 6]
+        assert_eq!(vec![1, 3, 6, 7, 8], sw.iter_sorted().copied().collect::<Vec<_>>());
+        assert_eq!(Some(&1), sw.nth_smallest(0));
+        assert_eq!(Some(&6), sw.nth_smallest(2));
+        assert_eq!(Some(&8), sw.nth_smallest(4));
+        assert_eq!(None, sw.nth_smallest(5));
+    }
+
+    #[test]
+    fn sorted_nan() {
+        let mut sw: SlidingWindow<f64, 6> = SlidingWindow::new();
+
+        for &x in &[3.0, f64::NAN, 1.0, 4.0, f64::NAN, 2.0] {
+            sw.insert(x);
+        }
+
+        let sorted = sw.iter_sorted().copied().collect::<Vec<_>>();
+        assert_eq!(vec![1.0, 2.0, 3.0, 4.0], sorted[..4]);
+        assert!(sorted[4..].iter().all(|x| x.is_nan()));
+
+        assert_eq!(Some(&1.0), sw.nth_smallest(0));
+        assert_eq!(Some(&4.0), sw.nth_smallest(3));
+        assert!(sw.nth_smallest(4).unwrap().is_nan());
+    }
+
+    #[test]
+    fn binary_search() {
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+
+        assert_eq!(Err(0), sw.binary_search(&1));
+        assert_eq!(0, sw.partition_point(|&x| x < 1));
+
+        for i in 1..=7 {
+            sw.insert(i * 10);
+        }
+
+        // [30, 40, 50, 60, 70], wrapped in the internal array
+        for (idx, &x) in sw.iter().enumerate() {
+            assert_eq!(Ok(idx), sw.binary_search(&x));
+            assert_eq!(Err(idx), sw.binary_search(&(x - 5)));
+            assert_eq!(idx, sw.partition_point(|&y| y < x));
+            assert_eq!(idx + 1, sw.partition_point(|&y| y <= x));
+        }
+        assert_eq!(Err(5), sw.binary_search(&75));
+    }
+
+    #[test]
+    fn make_contiguous() {
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+
+        for i in 1..=7 {
+            sw.insert(i);
+        }
+
+        assert_eq!(&mut [3, 4, 5, 6, 7], sw.make_contiguous());
+        sw.insert(8);
+        assert_eq!(&mut [4, 5, 6, 7, 8], sw.make_contiguous());
+    }
+
+    #[test]
+    fn in_place_mutation() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        for i in 1..=6 {
+            sw.insert(i);
+        }
+
+        // [3, 4, 5, 6]
+        assert_eq!(4, sw.replace(1, 40));
+        sw.swap(0, 3);
+        assert_eq!(vec![6, 40, 5, 3], sw.iter().copied().collect::<Vec<_>>());
+
+        assert_eq!(40, sw.swap_remove(1));
+        assert_eq!(vec![6, 3, 5], sw.iter().copied().collect::<Vec<_>>());
+        assert_eq!(5, sw.swap_remove(2));
+        assert_eq!(vec![6, 3], sw.iter().copied().collect::<Vec<_>>());
+
+        sw.insert(7);
+        assert_eq!(vec![6, 3, 7], sw.iter().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "Trying to access uninitialized memory")]
+    fn swap_out_of_bounds() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+        sw.insert(1);
+        sw.swap(0, 1);
+    }
+
+    #[test]
+    fn iter_latest() {
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+
+        assert_eq!(0, sw.iter_latest(3).len());
+
+        for i in 1..=7 {
+            sw.insert(i);
+        }
+
+        assert_eq!(vec![5, 6, 7], sw.iter_latest(3).copied().collect::<Vec<_>>());
+        assert_eq!(vec![3, 4, 5, 6, 7], sw.iter_latest(10).copied().collect::<Vec<_>>());
+        assert_eq!(0, sw.iter_latest(0).len());
+    }
+
+    #[test]
+    fn iter_step_by() {
+        let mut sw: SlidingWindow<_, 7> = SlidingWindow::new();
+
+        assert_eq!(0, sw.iter_step_by(2).len());
+
+        for i in 1..=10 {
+            sw.insert(i);
+        }
+
+        // [4, 5, 6, 7, 8, 9, 10], wrapped in the internal array
+        assert_eq!(vec![4, 6, 8, 10], sw.iter_step_by(2).copied().collect::<Vec<_>>());
+        assert_eq!(vec![4, 7, 10], sw.iter_step_by(3).copied().collect::<Vec<_>>());
+        assert_eq!(vec![4], sw.iter_step_by(100).copied().collect::<Vec<_>>());
+        assert_eq!(7, sw.iter_step_by(1).len());
+    }
+
+    #[test]
+    fn zip() {
+        let mut setpoint: SlidingWindow<_, 4> = SlidingWindow::new();
+        let mut measured: SlidingWindow<_, 3> = SlidingWindow::new();
+
+        for i in 1..=6 {
+            setpoint.insert(i * 10);
+        }
+        for i in 1..=4 {
+            measured.insert(i * 11);
+        }
+
+        // [30, 40, 50, 60] and [22, 33, 44]
+        let errors = setpoint.zip(&measured).map(|(&s, &m)| s - m).collect::<Vec<_>>();
+        assert_eq!(vec![8, 7, 6], errors);
+        assert_eq!(3, setpoint.zip(&measured).len());
+        assert_eq!(21, setpoint.zip(&measured).fold(0, |acc, (&s, &m)| acc + s - m));
+    }
+
+    #[test]
+    fn nth_from_newest() {
+        let mut sw: SlidingWindow<_, 3> = SlidingWindow::new();
+
+        assert_eq!(None, sw.nth_from_newest(0));
+
+        sw.insert(1);
+        assert_eq!(Some(&1), sw.nth_from_newest(0));
+        assert_eq!(None, sw.nth_from_newest(1));
+
+        for i in 2..=5 {
+            sw.insert(i);
+        }
+        assert_eq!(Some(&5), sw.nth_from_newest(0));
+        assert_eq!(Some(&4), sw.nth_from_newest(1));
+        assert_eq!(Some(&3), sw.nth_from_newest(2));
+        assert_eq!(None, sw.nth_from_newest(3));
+    }
+
+    #[test]
+    fn rotate() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        for i in 1..=3 {
+            sw.insert(i);
+        }
+        sw.rotate_left(1);
+        assert_eq!(vec![2, 3, 1], sw.iter().copied().collect::<Vec<_>>());
+        sw.rotate_right(2);
+        assert_eq!(vec![3, 1, 2], sw.iter().copied().collect::<Vec<_>>());
+
+        sw.insert(4);
+        sw.insert(5);
+        sw.rotate_left(3);
+        assert_eq!(vec![5, 1, 2, 4], sw.iter().copied().collect::<Vec<_>>());
+        sw.rotate_right(4);
+        assert_eq!(vec![5, 1, 2, 4], sw.iter().copied().collect::<Vec<_>>());
+
+        // The element at the first position is the next one to be replaced
+        assert_eq!(Some(5), sw.insert(6));
+    }
+
     #[test]
     fn unordered_iter() {
-        let mut sw: SlidingWindow<_, U4> = SlidingWindow::new();
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        sw.insert(1);
+        sw.insert(2);
+        sw.insert(3);
+        sw.insert(4);
+        sw.insert(5);
+        sw.insert(6);
+
+        assert_eq!(18, sw.iter_unordered().sum::<i32>());
+    }
+
+    #[test]
+    fn newest_first_iter() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        sw.insert(1);
+        sw.insert(2);
+        sw.insert(3);
+
+        let mut iter = sw.iter_newest_first();
+        assert_eq!(3, iter.len());
+        assert_eq!(Some(&3), iter.next());
+        assert_eq!(Some(&2), iter.next());
+        assert_eq!(Some(&1), iter.next());
+        assert_eq!(None, iter.next());
+
+        sw.insert(4);
+        sw.insert(5);
+        sw.insert(6);
+
+        let newest_first: [i32; 4] = [6, 5, 4, 3];
+        assert!(sw.iter_newest_first().eq(newest_first.iter()));
+    }
+
+    #[test]
+    fn windows() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
 
         sw.insert(1);
+        assert_eq!(0, sw.windows(2).len());
+
         sw.insert(2);
         sw.insert(3);
         sw.insert(4);
         sw.insert(5);
         sw.insert(6);
 
-        assert_eq!(18, sw.iter_unordered().sum());
+        let mut windows = sw.windows(3);
+        assert_eq!(2, windows.len());
+        assert!(windows.next().unwrap().eq([3, 4, 5].iter()));
+        assert!(windows.next().unwrap().eq([4, 5, 6].iter()));
+        assert!(windows.next().is_none());
+    }
+
+    #[test]
+    fn chunks() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        sw.insert(1);
+        sw.insert(2);
+        sw.insert(3);
+        sw.insert(4);
+        sw.insert(5);
+
+        let mut chunks = sw.chunks(3);
+        assert_eq!(2, chunks.len());
+        assert!(chunks.next().unwrap().eq([2, 3, 4].iter()));
+        assert!(chunks.next().unwrap().eq([5].iter()));
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn deltas() {
+        let mut sw: SlidingWindow<i32, 4> = SlidingWindow::new();
+
+        assert_eq!(0, sw.deltas().len());
+
+        sw.insert(1);
+        assert_eq!(0, sw.deltas().len());
+
+        sw.insert(2);
+        sw.insert(4);
+        sw.insert(7);
+        sw.insert(5);
+
+        assert_eq!(3, sw.deltas().len());
+        assert!(sw.deltas().eq([2, 3, -2].iter().copied()));
+    }
+
+    #[test]
+    fn process_segments() {
+        let mut sw: SlidingWindow<u8, 4> = SlidingWindow::new();
+
+        let collect = |mut segments: Vec<Vec<u8>>, segment: &[u8]| {
+            segments.push(segment.to_vec());
+            Ok::<_, ()>(segments)
+        };
+
+        assert_eq!(Ok(Vec::<Vec<u8>>::new()), sw.process_segments(Vec::new(), collect));
+
+        sw.insert(1);
+        sw.insert(2);
+        assert_eq!(Ok(vec![vec![1, 2]]), sw.process_segments(Vec::new(), collect));
+
+        sw.insert(3);
+        sw.insert(4);
+        sw.insert(5);
+        assert_eq!(Ok(vec![vec![2, 3, 4], vec![5]]), sw.process_segments(Vec::new(), collect));
+
+        // Stops after the first segment
+        let mut calls = 0;
+        let result = sw.process_segments((), |_, segment| {
+            calls += 1;
+            if segment.contains(&3) { Err(segment.len()) } else { Ok(()) }
+        });
+        assert_eq!(Err(3), result);
+        assert_eq!(1, calls);
+    }
+
+    #[test]
+    fn iter_pairs() {
+        let mut sw: SlidingWindow<i32, 4> = SlidingWindow::new();
+
+        assert_eq!(0, sw.iter_pairs().len());
+
+        sw.insert(1);
+        assert_eq!(0, sw.iter_pairs().len());
+
+        sw.insert(2);
+        sw.insert(4);
+        sw.insert(7);
+        sw.insert(5);
+
+        // The pairs span the end of the internal array
+        assert_eq!(3, sw.iter_pairs().len());
+        assert!(sw.iter_pairs().eq([(&2, &4), (&4, &7), (&7, &5)].iter().copied()));
+    }
+
+    #[test]
+    fn const_new() {
+        static LOG: SlidingWindow<u16, 32> = SlidingWindow::new();
+        const EMPTY: SlidingWindow<u16, 32> = SlidingWindow::new();
+
+        assert_eq!(0, LOG.count());
+
+        let mut sw = EMPTY;
+        sw.insert(1);
+
+        assert_eq!(1, sw.count());
+        assert_eq!(0, EMPTY.count());
+    }
+
+    #[test]
+    fn single_slot() {
+        let mut sw: SlidingWindow<_, 1> = SlidingWindow::new();
+
+        assert_eq!(None, sw.insert(1));
+        assert!(sw.is_full());
+        assert_eq!(Some(1), sw.insert(2));
+        assert_eq!(Some(2), sw.insert(3));
+
+        assert_eq!(1, sw.count());
+        assert!(sw.iter().eq([3].iter()));
+        assert!(sw.iter_newest_first().eq([3].iter()));
+        assert_eq!(Some(&3), sw.nth_from_newest(0));
+        assert_eq!(0, sw.deltas().count());
+
+        sw.rotate_left(1);
+        assert_eq!(3, sw[0]);
+
+        assert_eq!(3, sw.swap_remove(0));
+        assert_eq!(0, sw.count());
+        assert_eq!(None, sw.insert(4));
+        assert!(sw.iter().eq([4].iter()));
+    }
+
+    #[test]
+    fn insert_missing() {
+        let mut sw: SlidingWindow<u32, 4> = SlidingWindow::new();
+
+        sw.insert_missing(2, Fill::Repeat);
+        assert_eq!(0, sw.count());
+
+        sw.insert(1);
+        sw.insert(2);
+        sw.insert_missing(1, Fill::Repeat);
+        assert!(sw.iter().eq([1, 2, 2].iter()));
+
+        sw.insert_missing(2, Fill::Value(0));
+        assert!(sw.iter().eq([2, 2, 0, 0].iter()));
+
+        sw.insert_missing(usize::MAX, Fill::Value(7));
+        assert!(sw.iter().eq([7, 7, 7, 7].iter()));
+    }
+
+    #[test]
+    fn mark_missing() {
+        let mut sw: SlidingWindow<Option<u32>, 3> = SlidingWindow::new();
+
+        sw.insert(Some(1));
+        sw.mark_missing(1);
+        sw.insert(Some(2));
+        assert!(sw.iter().eq([Some(1), None, Some(2)].iter()));
+
+        sw.mark_missing(5);
+        assert!(sw.iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn clone() {
+        let mut sw: SlidingWindow<_, 3> = SlidingWindow::new();
+
+        sw.insert(1);
+        sw.insert(2);
+        sw.insert(3);
+        sw.insert(4);
+
+        let copy = sw.clone();
+        sw.insert(5);
+
+        assert!(copy.iter().eq([2, 3, 4].iter()));
+    }
+
+    #[test]
+    fn fmt_compact() {
+        let mut sw: SlidingWindow<f32, 3> = SlidingWindow::new();
+
+        assert_eq!("[] (0/3)", format!("{:?}", sw));
+
+        sw.insert(1.0);
+        sw.insert(2.0);
+        sw.insert(3.0);
+        sw.insert(4.5);
+
+        assert_eq!("[2.0, 3.0, 4.5] (3/3)", format!("{:?}", sw));
+        assert_eq!("[2.00, 3.00, 4.50] (3/3)", format!("{:.2?}", sw));
+        assert_eq!("[2.0, 3.0, 4.5] (3/3)", format!("{}", sw.debug_oldest_first()));
+    }
+
+    #[test]
+    fn drops_elements() {
+        use std::rc::Rc;
+
+        let item = Rc::new(());
+        let mut sw: SlidingWindow<_, 2> = SlidingWindow::new();
+
+        sw.insert(item.clone());
+        sw.insert(item.clone());
+        sw.insert(item.clone());
+        assert_eq!(3, Rc::strong_count(&item));
+
+        drop(sw);
+        assert_eq!(1, Rc::strong_count(&item));
     }
 
     #[test]
     #[should_panic(expected = "Trying to access uninitialized memory")]
     fn index_to_uninited() {
-        let mut sw: SlidingWindow<_, U4> = SlidingWindow::new();
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
 
         sw.insert(1);
         sw.insert(2);
         sw.insert(3);
 
-        sw[3];
+        let _ = &sw[3];
     }
 }
\ No newline at end of file
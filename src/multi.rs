@@ -0,0 +1,172 @@
+//! Sliding window of multi-channel samples that are kept aligned.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::multi::MultiWindow;
+//!
+//! // 3-axis accelerometer readings
+//! let mut window: MultiWindow<i16, 8, 3> = MultiWindow::new();
+//!
+//! window.insert([1, 2, 3]);
+//! window.insert([4, 5, 6]);
+//!
+//! assert_eq!(7, window.channel(1).sum::<i16>());
+//! assert_eq!(Some([&4, &5, &6]), window.rows().last());
+//! ```
+
+use crate::{SlidingWindow, Iter};
+
+/// A sliding window that stores one sample per channel for each insertion.
+pub struct MultiWindow<T, const N: usize, const CHANNELS: usize> {
+    window: SlidingWindow<[T; CHANNELS], N>
+}
+
+impl<T, const N: usize, const CHANNELS: usize> Default for MultiWindow<T, N, CHANNELS> {
+    fn default() -> Self {
+        Self {
+            window: SlidingWindow::new()
+        }
+    }
+}
+
+impl<T, const N: usize, const CHANNELS: usize> MultiWindow<T, N, CHANNELS> {
+    /// Returns an empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert one sample for every channel into the window.
+    ///
+    /// If the window is full, this method will remove and return the oldest samples.
+    pub fn insert(&mut self, row: [T; CHANNELS]) -> Option<[T; CHANNELS]> {
+        self.window.insert(row)
+    }
+
+    /// Removes all samples from the window.
+    pub fn clear(&mut self) {
+        self.window.clear();
+    }
+
+    /// Returns `true` if the window is full.
+    pub fn is_full(&self) -> bool {
+        self.window.is_full()
+    }
+
+    /// Returns the number of samples stored in each channel.
+    pub fn count(&self) -> usize {
+        self.window.count()
+    }
+
+    /// Returns an iterator over the samples of a single channel.
+    ///
+    /// The iterator starts at the oldest sample and ends with the newest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` is not smaller than `CHANNELS`.
+    pub fn channel(&self, channel: usize) -> ChannelIter<'_, T, CHANNELS> {
+        assert!(channel < CHANNELS, "Channel index out of range");
+
+        ChannelIter {
+            iter: self.window.iter(),
+            channel
+        }
+    }
+
+    /// Returns an iterator over the samples of all channels.
+    ///
+    /// The iterator starts at the oldest samples and ends with the newest.
+    pub fn rows(&self) -> Rows<'_, T, CHANNELS> {
+        Rows {
+            iter: self.window.iter()
+        }
+    }
+
+    /// Returns the underlying window.
+    pub fn window(&self) -> &SlidingWindow<[T; CHANNELS], N> {
+        &self.window
+    }
+}
+
+/// Iterator over the samples of a single channel.
+///
+/// Returned by [`MultiWindow::channel`].
+pub struct ChannelIter<'a, T, const CHANNELS: usize> {
+    iter: Iter<'a, [T; CHANNELS]>,
+    channel: usize
+}
+
+impl<'a, T, const CHANNELS: usize> Iterator for ChannelIter<'a, T, CHANNELS> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|row| &row[self.channel])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, const CHANNELS: usize> ExactSizeIterator for ChannelIter<'a, T, CHANNELS> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Iterator over the samples of all channels.
+///
+/// Returned by [`MultiWindow::rows`].
+pub struct Rows<'a, T, const CHANNELS: usize> {
+    iter: Iter<'a, [T; CHANNELS]>
+}
+
+impl<'a, T, const CHANNELS: usize> Iterator for Rows<'a, T, CHANNELS> {
+    type Item = [&'a T; CHANNELS];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|row| row.each_ref())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, const CHANNELS: usize> ExactSizeIterator for Rows<'a, T, CHANNELS> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn channels_stay_aligned() {
+        let mut window: MultiWindow<u8, 2, 3> = MultiWindow::new();
+
+        window.insert([1, 2, 3]);
+        window.insert([4, 5, 6]);
+        assert_eq!(Some([1, 2, 3]), window.insert([7, 8, 9]));
+
+        assert_eq!(2, window.channel(0).len());
+        assert!(window.channel(0).eq([4, 7].iter()));
+        assert!(window.channel(2).eq([6, 9].iter()));
+
+        let mut rows = window.rows();
+        assert_eq!(Some([&4, &5, &6]), rows.next());
+        assert_eq!(Some([&7, &8, &9]), rows.next());
+        assert_eq!(None, rows.next());
+    }
+
+    #[test]
+    #[should_panic(expected = "Channel index out of range")]
+    fn channel_out_of_range() {
+        let window: MultiWindow<u8, 2, 3> = MultiWindow::new();
+
+        window.channel(3);
+    }
+}
@@ -0,0 +1,407 @@
+//! Numeric types that statistics and signal processing helpers can work with.
+//!
+//! [`Sample`] is implemented for the primitive floating point and integer types. It covers sums,
+//! products and square roots, which are exact or rounded down for integers. Statistics that rely
+//! on fractional intermediate results or negative differences, like the running variance or
+//! interpolation, require [`Real`], which is only implemented by the signed non-integer types.
+//!
+//! Further types are supported by enabling features:
+//!
+//! * `fixed`: the fixed point types of the `fixed` crate
+//! * `num-traits`: any type implementing the `num-traits` numeric traits, through [`Num`]
+//! * `micromath`: `micromath::F32`, which uses fast approximations for square roots
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::SlidingWindow;
+//!
+//! let mut window: SlidingWindow<i32, 3> = SlidingWindow::new();
+//!
+//! window.insert(10);
+//! window.insert(20);
+//! window.insert(30);
+//!
+//! assert_eq!(140, window.convolve(&[1, 2, 3]));
+//! ```
+
+use core::convert::TryFrom;
+use core::ops::{Add, Sub, Mul, Div};
+
+/// Numeric types that sums, products and roots can be computed over.
+pub trait Sample:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self> {
+
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// Converts an element count to this type, or returns `None` if it is not representable.
+    fn from_usize(n: usize) -> Option<Self>;
+
+    /// Returns the square root of `self`.
+    fn sqrt(self) -> Self;
+}
+
+/// Signed numeric types that approximate real numbers.
+///
+/// Unlike integers, these can represent negative differences and the results of division are
+/// not rounded to whole numbers. Statistics that would give wrong results for integers are not
+/// available for them:
+///
+/// ```compile_fail
+/// use sliding_window::stats::RollingStats;
+///
+/// let stats: RollingStats<i32, 4> = RollingStats::new();
+/// ```
+///
+/// ```compile_fail
+/// use sliding_window::SlidingWindow;
+///
+/// let window: SlidingWindow<u16, 4> = SlidingWindow::new();
+/// window.interpolate(0.5);
+/// ```
+pub trait Real: Sample {
+    /// Converts a floating point number to this type.
+    fn from_f32(f: f32) -> Self;
+}
+
+impl Sample for f32 {
+    const ZERO: Self = 0.0;
+
+    fn from_usize(n: usize) -> Option<Self> {
+        Some(n as f32)
+    }
+
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+}
+
+impl Real for f32 {
+    fn from_f32(f: f32) -> Self {
+        f
+    }
+}
+
+impl Sample for f64 {
+    const ZERO: Self = 0.0;
+
+    fn from_usize(n: usize) -> Option<Self> {
+        Some(n as f64)
+    }
+
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+}
+
+impl Real for f64 {
+    fn from_f32(f: f32) -> Self {
+        f as f64
+    }
+}
+
+macro_rules! impl_sample_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Sample for $t {
+                const ZERO: Self = 0;
+
+                fn from_usize(n: usize) -> Option<Self> {
+                    <$t>::try_from(n).ok()
+                }
+
+                /// Returns the integer square root of `self`, rounded down.
+                fn sqrt(self) -> Self {
+                    self.isqrt()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_sample_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Sample for $t {
+                const ZERO: Self = 0;
+
+                fn from_usize(n: usize) -> Option<Self> {
+                    <$t>::try_from(n).ok()
+                }
+
+                /// Returns the integer square root of `self`, rounded down, or 0 for negative
+                /// numbers.
+                fn sqrt(self) -> Self {
+                    if self < 0 { 0 } else { self.isqrt() }
+                }
+            }
+        )*
+    };
+}
+
+impl_sample_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_sample_signed!(i8, i16, i32, i64, i128, isize);
+
+/// How to handle arithmetic overflow when accumulating samples.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// The computation fails.
+    Checked,
+
+    /// Intermediate results are clamped to the range of the type.
+    Saturating,
+
+    /// Intermediate results wrap around at the boundaries of the type.
+    Wrapping
+}
+
+/// Samples that can be added with an explicit [`Overflow`] policy.
+pub trait Accumulate: Sample {
+    /// Adds `rhs` to `self`, handling overflow according to `policy`.
+    ///
+    /// Returns `None` if the addition overflows and `policy` is [`Overflow::Checked`].
+    fn add_with(self, rhs: Self, policy: Overflow) -> Option<Self>;
+
+    /// Returns the mean of the `count` elements of `items`, rounded towards zero.
+    ///
+    /// The sum is computed in a 128-bit integer, so it does not overflow unless the elements are
+    /// 128-bit integers themselves. Returns `None` if `count` is zero or the sum overflows.
+    fn wide_mean<I>(items: I, count: usize) -> Option<Self>
+        where
+            I: Iterator<Item = Self>;
+}
+
+macro_rules! impl_accumulate {
+    ($wide:ty: $($t:ty),*) => {
+        $(
+            impl Accumulate for $t {
+                fn add_with(self, rhs: Self, policy: Overflow) -> Option<Self> {
+                    match policy {
+                        Overflow::Checked => self.checked_add(rhs),
+                        Overflow::Saturating => Some(self.saturating_add(rhs)),
+                        Overflow::Wrapping => Some(self.wrapping_add(rhs))
+                    }
+                }
+
+                fn wide_mean<I>(mut items: I, count: usize) -> Option<Self>
+                    where
+                        I: Iterator<Item = Self> {
+                    let count = <$wide>::try_from(count).ok().filter(|&count| count > 0)?;
+                    let sum = items.try_fold(0, |sum: $wide, x| sum.checked_add(<$wide>::try_from(x).ok()?))?;
+
+                    <$t>::try_from(sum / count).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_accumulate!(u128: u8, u16, u32, u64, u128, usize);
+impl_accumulate!(i128: i8, i16, i32, i64, i128, isize);
+
+#[cfg(feature = "micromath")]
+impl Sample for micromath::F32 {
+    const ZERO: Self = Self::ZERO;
+
+    fn from_usize(n: usize) -> Option<Self> {
+        Some(Self(n as f32))
+    }
+
+    /// Returns an approximation of the square root of `self`.
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+}
+
+#[cfg(feature = "micromath")]
+impl Real for micromath::F32 {
+    fn from_f32(f: f32) -> Self {
+        Self(f)
+    }
+}
+
+#[cfg(feature = "fixed")]
+mod fixed_impls {
+    use fixed::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128};
+    use fixed::{FixedI8, FixedI16, FixedI32, FixedI64, FixedI128};
+    use fixed::{FixedU8, FixedU16, FixedU32, FixedU64, FixedU128};
+
+    use super::{Real, Sample};
+
+    macro_rules! impl_sample_fixed {
+        ($($t:ident: $bound:ident),*) => {
+            $(
+                impl<Frac> Sample for $t<Frac>
+                    where
+                        Frac: $bound {
+                    const ZERO: Self = Self::ZERO;
+
+                    fn from_usize(n: usize) -> Option<Self> {
+                        Self::checked_from_num(n)
+                    }
+
+                    /// Returns the square root of `self`, or 0 for negative numbers.
+                    fn sqrt(self) -> Self {
+                        if self < Self::ZERO { Self::ZERO } else { self.sqrt() }
+                    }
+                }
+            )*
+        };
+    }
+
+    macro_rules! impl_real_fixed {
+        ($($t:ident: $bound:ident),*) => {
+            $(
+                impl<Frac> Real for $t<Frac>
+                    where
+                        Frac: $bound {
+                    fn from_f32(f: f32) -> Self {
+                        Self::from_num(f)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_sample_fixed!(
+        FixedI8: LeEqU8, FixedI16: LeEqU16, FixedI32: LeEqU32, FixedI64: LeEqU64, FixedI128: LeEqU128,
+        FixedU8: LeEqU8, FixedU16: LeEqU16, FixedU32: LeEqU32, FixedU64: LeEqU64, FixedU128: LeEqU128
+    );
+    impl_real_fixed!(
+        FixedI8: LeEqU8, FixedI16: LeEqU16, FixedI32: LeEqU32, FixedI64: LeEqU64, FixedI128: LeEqU128
+    );
+}
+
+/// Adapter that implements [`Sample`] for types implementing the `num-traits` numeric traits.
+///
+/// Square roots are computed by converting to `f64` and back. [`Real`] is implemented for
+/// floating point types.
+#[cfg(feature = "num-traits")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Num<T>(pub T);
+
+#[cfg(feature = "num-traits")]
+mod num_traits_impls {
+    use core::ops::{Add, Sub, Mul, Div};
+
+    use num_traits::{ConstZero, NumCast};
+    use num_traits::float::FloatCore;
+
+    use super::{Num, Real, Sample};
+
+    macro_rules! impl_op {
+        ($($tr:ident::$f:ident),*) => {
+            $(
+                impl<T> $tr for Num<T>
+                    where
+                        T: $tr<Output = T> {
+                    type Output = Self;
+
+                    fn $f(self, rhs: Self) -> Self {
+                        Num(self.0.$f(rhs.0))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_op!(Add::add, Sub::sub, Mul::mul, Div::div);
+
+    impl<T> Sample for Num<T>
+        where
+            T: num_traits::Num + ConstZero + NumCast + Copy + PartialOrd {
+        const ZERO: Self = Num(T::ZERO);
+
+        fn from_usize(n: usize) -> Option<Self> {
+            T::from(n).map(Num)
+        }
+
+        /// Returns the square root of `self`, or 0 for negative numbers.
+        fn sqrt(self) -> Self {
+            match self.0.to_f64() {
+                Some(value) if value > 0.0 => Num(T::from(libm::sqrt(value)).unwrap_or(T::ZERO)),
+                _ => Self::ZERO
+            }
+        }
+    }
+
+    impl<T> Real for Num<T>
+        where
+            T: FloatCore + ConstZero {
+        /// Converts `f`, panicking if it is not representable by `T`.
+        fn from_f32(f: f32) -> Self {
+            Num(<T as NumCast>::from(f).expect("Value not representable"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Sample;
+
+    #[test]
+    fn integer_sqrt() {
+        assert_eq!(3, 15u16.sqrt());
+        assert_eq!(4, 16i32.sqrt());
+        assert_eq!(0, (-4i32).sqrt());
+    }
+
+    #[test]
+    fn integer_from_usize() {
+        assert_eq!(Some(255), u8::from_usize(255));
+        assert_eq!(None, u8::from_usize(256));
+        assert_eq!(None, i8::from_usize(128));
+    }
+
+    #[test]
+    fn add_with_policy() {
+        use super::{Accumulate, Overflow};
+
+        assert_eq!(None, 200u8.add_with(100, Overflow::Checked));
+        assert_eq!(Some(255), 200u8.add_with(100, Overflow::Saturating));
+        assert_eq!(Some(44), 200u8.add_with(100, Overflow::Wrapping));
+        assert_eq!(Some(-128), (-100i8).add_with(-100, Overflow::Saturating));
+
+        assert_eq!(Some(-1), i8::wide_mean([-100, 100, -3].iter().copied(), 3));
+        assert_eq!(Some(127), i8::wide_mean([127, 127].iter().copied(), 2));
+        assert_eq!(None, u128::wide_mean([u128::MAX, 1].iter().copied(), 2));
+        assert_eq!(None, u8::wide_mean(core::iter::empty(), 0));
+    }
+
+    #[test]
+    #[cfg(feature = "fixed")]
+    fn fixed_point() {
+        use fixed::types::I16F16;
+        use super::Real;
+
+        assert_eq!(I16F16::from_num(3), <I16F16 as Sample>::sqrt(I16F16::from_num(9)));
+        assert_eq!(I16F16::from_num(2.5), I16F16::from_f32(2.5));
+        assert_eq!(None, I16F16::from_usize(1 << 15));
+    }
+
+    #[test]
+    #[cfg(feature = "micromath")]
+    fn micromath() {
+        use micromath::F32;
+
+        let root = <F32 as Sample>::sqrt(F32(16.0));
+        assert!((root.0 - 4.0).abs() < 0.5);
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn num_traits_adapter() {
+        use super::Num;
+
+        assert_eq!(Num(5i64), Num(30i64).sqrt());
+        assert_eq!(Some(Num(4u8)), Num::from_usize(2).map(|n| Num(2u8) + n));
+        assert_eq!(None, Num::<u8>::from_usize(256));
+    }
+}
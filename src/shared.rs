@@ -0,0 +1,106 @@
+//! Sliding window that can be shared between interrupt handlers and the main program.
+//!
+//! This module is available with the `critical-section` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::shared::SharedSlidingWindow;
+//!
+//! static WINDOW: SharedSlidingWindow<u16, 4> = SharedSlidingWindow::new();
+//!
+//! // In the interrupt handler
+//! WINDOW.insert(42);
+//!
+//! // In the main loop
+//! let snapshot = WINDOW.snapshot();
+//! assert_eq!(42, snapshot[0]);
+//! ```
+
+use core::cell::RefCell;
+use critical_section::Mutex;
+use crate::SlidingWindow;
+
+/// A sliding window protected by a critical section.
+///
+/// Every operation runs inside a critical section, so the window can be accessed from shared
+/// references, including interrupt handlers.
+pub struct SharedSlidingWindow<IT, const N: usize> {
+    window: Mutex<RefCell<SlidingWindow<IT, N>>>
+}
+
+impl<IT, const N: usize> Default for SharedSlidingWindow<IT, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<IT, const N: usize> SharedSlidingWindow<IT, N> {
+    /// Returns an empty sliding window object.
+    ///
+    /// This function can be used to initialize a `static` window.
+    pub const fn new() -> Self {
+        Self {
+            window: Mutex::new(RefCell::new(SlidingWindow::new()))
+        }
+    }
+
+    /// Insert an element into the window.
+    ///
+    /// If the window is full, this method will remove and return the oldest element.
+    pub fn insert(&self, t: IT) -> Option<IT> {
+        self.with(|window| window.insert(t))
+    }
+
+    /// Removes all elements from the window.
+    pub fn clear(&self) {
+        self.with(|window| window.clear())
+    }
+
+    /// Returns the number of elements stored in the window.
+    pub fn count(&self) -> usize {
+        self.with(|window| window.count())
+    }
+
+    /// Returns a copy of the current contents of the window.
+    pub fn snapshot(&self) -> SlidingWindow<IT, N>
+        where
+            IT: Clone {
+        self.with(|window| window.clone())
+    }
+
+    /// Calls `f` with exclusive access to the window, inside a critical section.
+    ///
+    /// Keep `f` short, as interrupts are disabled while it runs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within `f`.
+    pub fn with<R>(&self, f: impl FnOnce(&mut SlidingWindow<IT, N>) -> R) -> R {
+        critical_section::with(|cs| f(&mut self.window.borrow_ref_mut(cs)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shared_access() {
+        let window: SharedSlidingWindow<u32, 2> = SharedSlidingWindow::new();
+
+        assert_eq!(None, window.insert(1));
+        assert_eq!(None, window.insert(2));
+        assert_eq!(Some(1), window.insert(3));
+        assert_eq!(2, window.count());
+
+        let snapshot = window.snapshot();
+        window.insert(4);
+
+        assert!(snapshot.iter().eq([2, 3].iter()));
+        assert_eq!(7, window.with(|window| window.iter().sum::<u32>()));
+
+        window.clear();
+        assert_eq!(0, window.count());
+    }
+}
@@ -0,0 +1,191 @@
+//! Frozen copies of the contents of a window.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::SlidingWindow;
+//!
+//! let mut window: SlidingWindow<u8, 4> = SlidingWindow::new();
+//! window.insert(1);
+//! window.insert(2);
+//!
+//! let snapshot = window.snapshot();
+//! window.insert(3);
+//!
+//! assert_eq!(&[1, 2], snapshot.as_slice());
+//! assert_eq!(2, snapshot[1]);
+//! ```
+//!
+//! Snapshots can themselves be stored in a window, e.g. to keep the last frames of a
+//! sample buffer:
+//!
+//! ```rust
+//! use sliding_window::SlidingWindow;
+//! use sliding_window::snapshot::WindowSnapshot;
+//!
+//! let mut samples: SlidingWindow<i16, 4> = SlidingWindow::new();
+//! let mut frames: SlidingWindow<WindowSnapshot<i16, 4>, 8> = SlidingWindow::new();
+//!
+//! for sample in 0..6 {
+//!     samples.insert(sample);
+//!     frames.insert_snapshot_of(&samples);
+//! }
+//!
+//! assert_eq!(6, frames.count());
+//! assert_eq!(&[2, 3, 4, 5], frames[5].as_slice());
+//! ```
+
+use core::mem::MaybeUninit;
+
+use crate::{SlidingWindow, SlidingWindowBase, Storage, slice_assume_init};
+
+/// A copy of the contents of a [`SlidingWindow`], stored in the order of insertion.
+///
+/// The snapshot dereferences to a slice, with index 0 being the oldest element.
+#[derive(Clone, Copy)]
+pub struct WindowSnapshot<T, const N: usize>
+    where
+        T: Copy {
+    items: [MaybeUninit<T>; N],
+    count: usize
+}
+
+impl<T, const N: usize> WindowSnapshot<T, N>
+    where
+        T: Copy {
+
+    /// Returns the copied elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice_assume_init(&self.items[..self.count]) }
+    }
+}
+
+impl<T, const N: usize> Default for WindowSnapshot<T, N>
+    where
+        T: Copy {
+
+    /// Returns an empty snapshot.
+    fn default() -> Self {
+        Self {
+            items: [MaybeUninit::uninit(); N],
+            count: 0
+        }
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for WindowSnapshot<T, N>
+    where
+        T: Copy + core::fmt::Debug {
+
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T, const N: usize> PartialEq for WindowSnapshot<T, N>
+    where
+        T: Copy + PartialEq {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, const N: usize> core::ops::Deref for WindowSnapshot<T, N>
+    where
+        T: Copy {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a WindowSnapshot<T, N>
+    where
+        T: Copy {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<T, const N: usize> SlidingWindow<T, N>
+    where
+        T: Copy {
+
+    /// Copies the current elements of the window into a [`WindowSnapshot`].
+    pub fn snapshot(&self) -> WindowSnapshot<T, N> {
+        let (older, newer) = self.as_slices();
+        let mut items = [MaybeUninit::uninit(); N];
+
+        unsafe {
+            let dst = items.as_mut_ptr() as *mut T;
+            core::ptr::copy_nonoverlapping(older.as_ptr(), dst, older.len());
+            core::ptr::copy_nonoverlapping(newer.as_ptr(), dst.add(older.len()), newer.len());
+        }
+
+        WindowSnapshot {
+            items,
+            count: self.count()
+        }
+    }
+}
+
+impl<T, S, const M: usize> SlidingWindowBase<WindowSnapshot<T, M>, S>
+    where
+        S: Storage<WindowSnapshot<T, M>>,
+        T: Copy {
+
+    /// Inserts a snapshot of `window` into this window.
+    ///
+    /// If this window is full, this method will remove and return the oldest snapshot.
+    pub fn insert_snapshot_of(&mut self, window: &SlidingWindow<T, M>) -> Option<WindowSnapshot<T, M>> {
+        self.insert(window.snapshot())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::WindowSnapshot;
+    use crate::SlidingWindow;
+
+    #[test]
+    fn snapshot() {
+        let mut sw: SlidingWindow<_, 4> = SlidingWindow::new();
+
+        assert!(sw.snapshot().is_empty());
+
+        for i in 1..=6 {
+            sw.insert(i);
+        }
+
+        let snapshot = sw.snapshot();
+        let copy = snapshot;
+        sw.insert(7);
+
+        assert_eq!(&[3, 4, 5, 6], snapshot.as_slice());
+        assert_eq!(4, copy.len());
+        assert_eq!(18, (&copy).into_iter().sum::<i32>());
+    }
+
+    #[test]
+    fn window_of_snapshots() {
+        let mut sw: SlidingWindow<_, 3> = SlidingWindow::new();
+        let mut frames: SlidingWindow<_, 2> = SlidingWindow::new();
+
+        sw.insert(1);
+        frames.insert_snapshot_of(&sw);
+        sw.insert(2);
+        frames.insert_snapshot_of(&sw);
+        sw.insert(3);
+
+        let evicted = frames.insert_snapshot_of(&sw).unwrap();
+        assert_eq!(&[1], evicted.as_slice());
+        assert_eq!(sw.snapshot(), frames[1]);
+        assert_eq!("[1, 2]", format!("{:?}", frames[0]));
+        assert!(WindowSnapshot::<u8, 4>::default().is_empty());
+    }
+}
@@ -0,0 +1,371 @@
+//! Sliding window that can be written and read concurrently without locking.
+//!
+//! The window is split into a [`Producer`] that inserts samples (for example, from an interrupt
+//! handler) and a [`Consumer`] that takes consistent snapshots of the contents (for example, from
+//! the main loop). Neither side ever blocks the other, and the implementation only requires
+//! atomic loads and stores, so it works on targets without compare-and-swap instructions.
+//!
+//! Instead of copying the whole window, the consumer can also [iterate](Consumer::iter) over it
+//! directly. Like a seqlock, the sequence numbers of the insertions tell the iterator whether
+//! the producer overwrote an element in the meantime, and [`Consumer::read`] retries until it
+//! gets through without interruption.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::spsc::SlidingWindow;
+//!
+//! let mut window: SlidingWindow<u16, 4> = SlidingWindow::new();
+//! let (mut producer, consumer) = window.split();
+//!
+//! producer.insert(1);
+//! producer.insert(2);
+//!
+//! let snapshot = consumer.snapshot();
+//! assert!(snapshot.iter().eq([1, 2].iter()));
+//! ```
+
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicUsize, Ordering, fence};
+
+/// A sliding window that supports one concurrent writer and one concurrent reader.
+///
+/// Sequence numbers count the insertions. They run from 0 up to a large multiple of `N`, after
+/// which they restart from `N`, so that `seq % N` is always the slot of the sample and a window
+/// that has been full once is never considered partially filled again.
+pub struct SlidingWindow<IT, const N: usize> {
+    items: UnsafeCell<[MaybeUninit<IT>; N]>,
+
+    /// Sequence number of the next sample whose write has started.
+    claimed: AtomicUsize,
+
+    /// Sequence number of the next sample whose write has not yet finished.
+    published: AtomicUsize
+}
+
+unsafe impl<IT, const N: usize> Sync for SlidingWindow<IT, N>
+    where
+        IT: Copy + Send {}
+
+impl<IT, const N: usize> Default for SlidingWindow<IT, N>
+    where
+        IT: Copy {
+
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<IT, const N: usize> SlidingWindow<IT, N>
+    where
+        IT: Copy {
+
+    /// Largest sequence number plus one.
+    const LIMIT: usize = usize::MAX / N * N;
+
+    /// Returns an empty sliding window object.
+    ///
+    /// This function can be used to initialize a `static` window.
+    ///
+    /// A capacity of zero is rejected at compile time.
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity must be non-zero") };
+
+        Self {
+            items: UnsafeCell::new([const { MaybeUninit::uninit() }; N]),
+            claimed: AtomicUsize::new(0),
+            published: AtomicUsize::new(0)
+        }
+    }
+
+    /// Splits the window into its writing and reading halves.
+    pub fn split(&mut self) -> (Producer<'_, IT, N>, Consumer<'_, IT, N>) {
+        (Producer { window: self }, Consumer { window: self })
+    }
+
+    fn next_seq(seq: usize) -> usize {
+        if seq == Self::LIMIT - 1 { N } else { seq + 1 }
+    }
+
+    /// Returns the number of insertions that took `from` to `to`.
+    fn distance(from: usize, to: usize) -> usize {
+        if to >= from {
+            to - from
+        } else {
+            (Self::LIMIT - from) + (to - N)
+        }
+    }
+
+    fn slot(&self, seq: usize) -> *mut MaybeUninit<IT> {
+        let items = self.items.get() as *mut MaybeUninit<IT>;
+        unsafe { items.add(seq % N) }
+    }
+}
+
+/// Writing half of a [`SlidingWindow`].
+pub struct Producer<'a, IT, const N: usize> {
+    window: &'a SlidingWindow<IT, N>
+}
+
+impl<'a, IT, const N: usize> Producer<'a, IT, N>
+    where
+        IT: Copy {
+
+    /// Insert an element into the window.
+    ///
+    /// If the window is full, the oldest element is overwritten.
+    pub fn insert(&mut self, t: IT) {
+        let window = self.window;
+        let seq = window.published.load(Ordering::Relaxed);
+        let next = SlidingWindow::<IT, N>::next_seq(seq);
+
+        window.claimed.store(next, Ordering::Relaxed);
+        fence(Ordering::Release);
+
+        // The consumer may be reading this slot, but it will discard whatever it read.
+        unsafe { core::ptr::write_volatile(window.slot(seq), MaybeUninit::new(t)) };
+
+        window.published.store(next, Ordering::Release);
+    }
+}
+
+/// Reading half of a [`SlidingWindow`].
+pub struct Consumer<'a, IT, const N: usize> {
+    window: &'a SlidingWindow<IT, N>
+}
+
+impl<'a, IT, const N: usize> Consumer<'a, IT, N>
+    where
+        IT: Copy {
+
+    /// Returns a copy of the current contents of the window.
+    ///
+    /// If the producer inserts elements while the snapshot is being taken, the elements that may
+    /// have been overwritten in the meantime are left out, so the snapshot may hold fewer
+    /// elements than the window. The elements in the snapshot are always the newest ones at the
+    /// time the snapshot was started, in order, without gaps.
+    pub fn snapshot(&self) -> crate::SlidingWindow<IT, N> {
+        let window = self.window;
+        let end = window.published.load(Ordering::Acquire);
+        let count = core::cmp::min(end, N);
+        // Slots only depend on the sequence number modulo `N`, so the sequence numbers of the
+        // stored elements can be counted from `start` even if they restarted in between.
+        let start = end - count;
+
+        let mut items: [MaybeUninit<IT>; N] = [const { MaybeUninit::uninit() }; N];
+        for (i, item) in items.iter_mut().take(count).enumerate() {
+            *item = unsafe { core::ptr::read_volatile(window.slot(start + i)) };
+        }
+
+        fence(Ordering::Acquire);
+        let claimed = window.claimed.load(Ordering::Relaxed);
+
+        // Every write that started since `end` may have overwritten one of the copied elements,
+        // oldest first. Free slots absorb the writes until the window fills up.
+        let overwritten = SlidingWindow::<IT, N>::distance(end, claimed);
+        let lost = core::cmp::min(overwritten.saturating_sub(N - count), count);
+
+        let mut snapshot = crate::SlidingWindow::new();
+        for item in &items[lost..count] {
+            snapshot.insert(unsafe { item.assume_init() });
+        }
+
+        snapshot
+    }
+
+    /// Returns an iterator over the contents of the window, starting with the oldest element.
+    ///
+    /// The iterator returns the elements stored at the time it was created, without copying them
+    /// up front. Every element is checked after it has been read, so the iterator never returns
+    /// an element that the producer was overwriting. If the producer overwrites an element before
+    /// the iterator reaches it, the iteration ends early, and
+    /// [`is_interrupted`](Iter::is_interrupted) returns `true`.
+    pub fn iter(&self) -> Iter<'a, IT, N> {
+        let window = self.window;
+        let end = window.published.load(Ordering::Acquire);
+        let count = core::cmp::min(end, N);
+
+        Iter {
+            window,
+            end,
+            count,
+            idx: 0,
+            interrupted: false
+        }
+    }
+
+    /// Calls `f` with an iterator over the contents of the window, until the producer doesn't
+    /// interrupt it.
+    ///
+    /// If the producer overwrites an element before `f` reads it, the result of `f` is discarded
+    /// and `f` is called again with a new iterator. Elements that `f` did not read are not
+    /// checked, so `f` may stop early. This may retry indefinitely if the producer keeps
+    /// overwriting the window faster than `f` can read it.
+    pub fn read<R>(&self, mut f: impl FnMut(&mut Iter<'a, IT, N>) -> R) -> R {
+        loop {
+            let mut iter = self.iter();
+            let result = f(&mut iter);
+            if !iter.is_interrupted() {
+                return result;
+            }
+        }
+    }
+}
+
+/// Iterator over the contents of a [`SlidingWindow`] that detects concurrent insertions.
+///
+/// Returned by [`Consumer::iter`].
+pub struct Iter<'a, IT, const N: usize> {
+    window: &'a SlidingWindow<IT, N>,
+
+    /// Sequence number of the element after the newest one.
+    end: usize,
+    count: usize,
+    idx: usize,
+    interrupted: bool
+}
+
+impl<'a, IT, const N: usize> Iter<'a, IT, N> {
+    /// Returns `true` if the iteration ended early because an element was overwritten by the
+    /// producer before it could be read.
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted
+    }
+}
+
+impl<'a, IT, const N: usize> Iterator for Iter<'a, IT, N>
+    where
+        IT: Copy {
+    type Item = IT;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.interrupted || self.idx == self.count {
+            return None;
+        }
+
+        let window = self.window;
+        let seq = self.end - self.count + self.idx;
+        let item = unsafe { core::ptr::read_volatile(window.slot(seq)) };
+
+        fence(Ordering::Acquire);
+        let claimed = window.claimed.load(Ordering::Relaxed);
+
+        // Like in `snapshot`, writes since `end` overwrite the stored elements oldest first, once
+        // the free slots are used up.
+        let overwritten = SlidingWindow::<IT, N>::distance(self.end, claimed);
+        if overwritten.saturating_sub(N - self.count) > self.idx {
+            self.interrupted = true;
+            return None;
+        }
+
+        self.idx += 1;
+        Some(unsafe { item.assume_init() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.interrupted {
+            (0, Some(0))
+        } else {
+            (0, Some(self.count - self.idx))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn snapshot() {
+        let mut window: SlidingWindow<u32, 3> = SlidingWindow::new();
+        let (mut producer, consumer) = window.split();
+
+        assert_eq!(0, consumer.snapshot().count());
+
+        producer.insert(1);
+        producer.insert(2);
+        assert!(consumer.snapshot().iter().eq([1, 2].iter()));
+
+        producer.insert(3);
+        producer.insert(4);
+        assert!(consumer.snapshot().iter().eq([2, 3, 4].iter()));
+    }
+
+    #[test]
+    fn iter_detects_overwrites() {
+        let mut window: SlidingWindow<u32, 3> = SlidingWindow::new();
+        let (mut producer, consumer) = window.split();
+
+        for i in 1..=3 {
+            producer.insert(i);
+        }
+
+        let mut iter = consumer.iter();
+        assert_eq!(Some(1), iter.next());
+
+        // Overwrites the element that has already been returned
+        producer.insert(4);
+        assert_eq!(Some(2), iter.next());
+        assert!(!iter.is_interrupted());
+
+        producer.insert(5);
+        producer.insert(6);
+        assert_eq!(None, iter.next());
+        assert!(iter.is_interrupted());
+
+        assert_eq!(15, consumer.read(|iter| iter.sum::<u32>()));
+    }
+
+    #[test]
+    fn sequence_numbers_restart() {
+        type Window = SlidingWindow<u32, 3>;
+
+        let mut window = Window::new();
+        window.claimed = AtomicUsize::new(Window::LIMIT - 2);
+        window.published = AtomicUsize::new(Window::LIMIT - 2);
+
+        let (mut producer, consumer) = window.split();
+        for i in 0..5 {
+            producer.insert(i);
+            let snapshot = consumer.snapshot();
+
+            assert_eq!(3, snapshot.count());
+            assert_eq!(i, snapshot[2]);
+        }
+        assert_eq!(Window::distance(Window::LIMIT - 2, 6), 5);
+    }
+
+    #[test]
+    fn concurrent_snapshots_are_consistent() {
+        let mut window: SlidingWindow<[u64; 4], 16> = SlidingWindow::new();
+        let (mut producer, consumer) = window.split();
+
+        std::thread::scope(|s| {
+            s.spawn(move || {
+                for i in 0..200_000 {
+                    producer.insert([i; 4]);
+                }
+            });
+
+            for _ in 0..10_000 {
+                let consistent = consumer.read(|iter| {
+                    let mut prev: Option<[u64; 4]> = None;
+                    iter.all(|item| {
+                        let consecutive = prev.is_none_or(|prev| prev[0] + 1 == item[0]);
+                        prev = Some(item);
+
+                        consecutive && item.iter().all(|&x| x == item[0])
+                    })
+                });
+                assert!(consistent);
+
+                let snapshot = consumer.snapshot();
+                for (older, newer) in snapshot.iter().zip(snapshot.iter().skip(1)) {
+                    assert!(older.iter().all(|&x| x == older[0]), "torn read: {:?}", older);
+                    assert_eq!(older[0] + 1, newer[0]);
+                }
+            }
+        });
+    }
+}
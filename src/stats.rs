@@ -0,0 +1,1393 @@
+//! Statistics that are updated incrementally as samples enter and leave the window.
+//!
+//! The [`Moments`] and [`SumOfSquares`] aggregators can also be attached to any window using
+//! [`SlidingWindow::with_aggregator`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::stats::RollingStats;
+//!
+//! let mut stats: RollingStats<f32, 4> = RollingStats::new();
+//!
+//! stats.insert(1.0);
+//! stats.insert(2.0);
+//! stats.insert(3.0);
+//! stats.insert(4.0);
+//!
+//! assert_eq!(Some(2.5), stats.mean());
+//!
+//! // Inserting into a full window evicts the oldest sample from the statistics, too
+//! stats.insert(5.0);
+//! assert_eq!(Some(3.5), stats.mean());
+//! assert_eq!(Some(14.0), stats.sum());
+//! ```
+
+use core::cmp::Ordering;
+use crate::{SlidingWindow, SlidingWindowBase, Storage, compare};
+use crate::num::{Accumulate, Overflow};
+use crate::aggregate::{Aggregator, Aggregated};
+use crate::wrapping::WrappingExt as _;
+
+pub use crate::num::Sample;
+use crate::num::Real;
+
+/// Converts an element count for use in an incremental update.
+///
+/// # Panics
+///
+/// Panics if `count` is not representable by `T`.
+fn count_as<T: Sample>(count: usize) -> T {
+    T::from_usize(count).expect("Element count not representable by the sample type")
+}
+
+/// Running mean and variance of the elements in a window, maintained using Welford's algorithm.
+///
+/// Adding or removing an element panics if the number of elements is not representable by `T`.
+#[derive(Clone, Copy, Debug)]
+pub struct Moments<T> {
+    count: usize,
+    sum: T,
+    mean: T,
+    m2: T
+}
+
+impl<T> Default for Moments<T>
+    where
+        T: Real {
+
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: T::ZERO,
+            mean: T::ZERO,
+            m2: T::ZERO
+        }
+    }
+}
+
+impl<T> Aggregator<T> for Moments<T>
+    where
+        T: Real {
+
+    fn on_insert(&mut self, item: &T) {
+        self.count += 1;
+        self.sum = self.sum + *item;
+
+        let delta = *item - self.mean;
+        self.mean = self.mean + delta / count_as(self.count);
+        self.m2 = self.m2 + delta * (*item - self.mean);
+    }
+
+    fn on_evict(&mut self, item: &T) {
+        self.count -= 1;
+
+        if self.count == 0 {
+            *self = Self::default();
+        } else {
+            self.sum = self.sum - *item;
+
+            let delta = *item - self.mean;
+            self.mean = self.mean - delta / count_as(self.count);
+            self.m2 = self.m2 - delta * (*item - self.mean);
+        }
+    }
+}
+
+impl<T> Moments<T>
+    where
+        T: Real {
+
+    /// Returns the sum of the elements, or `None` if there are none.
+    pub fn sum(&self) -> Option<T> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.sum)
+        }
+    }
+
+    /// Returns the mean of the elements, or `None` if there are none.
+    pub fn mean(&self) -> Option<T> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean)
+        }
+    }
+
+    /// Returns the population variance of the elements, or `None` if there are none.
+    pub fn variance(&self) -> Option<T> {
+        match self.count {
+            0 => None,
+            n => Some(self.m2() / T::from_usize(n)?)
+        }
+    }
+
+    /// Returns the sample variance of the elements, or `None` if there are fewer than two.
+    pub fn sample_variance(&self) -> Option<T> {
+        match self.count {
+            0 | 1 => None,
+            n => Some(self.m2() / T::from_usize(n - 1)?)
+        }
+    }
+
+    /// Returns the population standard deviation of the elements, or `None` if there are none.
+    pub fn std_dev(&self) -> Option<T> {
+        self.variance().map(T::sqrt)
+    }
+
+    fn m2(&self) -> T {
+        // Rounding errors may push the accumulator slightly below zero.
+        if self.m2 < T::ZERO { T::ZERO } else { self.m2 }
+    }
+}
+
+/// Running sum of squares of the elements in a window.
+#[derive(Clone, Copy, Debug)]
+pub struct SumOfSquares<T> {
+    count: usize,
+    sum: T
+}
+
+impl<T> Default for SumOfSquares<T>
+    where
+        T: Sample {
+
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: T::ZERO
+        }
+    }
+}
+
+impl<T> Aggregator<T> for SumOfSquares<T>
+    where
+        T: Sample {
+
+    fn on_insert(&mut self, item: &T) {
+        self.count += 1;
+        self.sum = self.sum + *item * *item;
+    }
+
+    fn on_evict(&mut self, item: &T) {
+        self.count -= 1;
+        self.sum = if self.count == 0 { T::ZERO } else { self.sum - *item * *item };
+    }
+}
+
+impl<T> SumOfSquares<T>
+    where
+        T: Sample {
+
+    /// Returns the sum of the squared elements.
+    pub fn sum_of_squares(&self) -> T {
+        // Rounding errors may push the accumulator slightly below zero.
+        if self.sum < T::ZERO { T::ZERO } else { self.sum }
+    }
+
+    /// Returns the root mean square of the elements, or `None` if there are none or their number is
+    /// not representable by `T`.
+    pub fn rms(&self) -> Option<T> {
+        match self.count {
+            0 => None,
+            n => Some((self.sum_of_squares() / T::from_usize(n)?).sqrt())
+        }
+    }
+}
+
+/// Running histogram of the elements in a window.
+///
+/// Elements are sorted into `BINS` bins by the classifier function. Bin indices that are out of
+/// range are counted in the last bin.
+///
+/// # Example
+///
+/// ```rust
+/// use sliding_window::SlidingWindow;
+/// use sliding_window::stats::Histogram;
+///
+/// let histogram = Histogram::<_, 4>::new(|us: &u32| *us as usize / 10);
+/// let mut jitter = SlidingWindow::<u32, 8>::new().with_aggregator(histogram);
+///
+/// jitter.insert(3);
+/// jitter.insert(12);
+/// jitter.insert(15);
+/// jitter.insert(120);
+///
+/// assert_eq!(&[1, 2, 0, 1], jitter.aggregator().bins());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Histogram<F, const BINS: usize> {
+    classify: F,
+    bins: [usize; BINS]
+}
+
+impl<F, const BINS: usize> Histogram<F, BINS> {
+    /// Returns an empty histogram that uses `classify` to determine the bin of an element.
+    ///
+    /// A histogram needs at least one bin, so `BINS = 0` is rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use sliding_window::stats::Histogram;
+    ///
+    /// let histogram = Histogram::<_, 0>::new(|x: &u32| *x as usize);
+    /// ```
+    pub fn new(classify: F) -> Self {
+        const { assert!(BINS > 0, "Histogram must have at least one bin") };
+
+        Self {
+            classify,
+            bins: [0; BINS]
+        }
+    }
+
+    /// Returns the number of elements in each bin.
+    pub fn bins(&self) -> &[usize; BINS] {
+        &self.bins
+    }
+
+    /// Returns the total number of elements in the histogram.
+    pub fn total(&self) -> usize {
+        self.bins.iter().sum()
+    }
+}
+
+impl<T, F, const BINS: usize> Aggregator<T> for Histogram<F, BINS>
+    where
+        F: Fn(&T) -> usize {
+
+    fn on_insert(&mut self, item: &T) {
+        let bin = core::cmp::min((self.classify)(item), BINS - 1);
+        self.bins[bin] += 1;
+    }
+
+    fn on_evict(&mut self, item: &T) {
+        let bin = core::cmp::min((self.classify)(item), BINS - 1);
+        self.bins[bin] -= 1;
+    }
+}
+
+/// A sliding window that keeps track of the sum, mean and variance of its contents.
+///
+/// The statistics are updated in O(1) on every insertion using Welford's algorithm.
+pub struct RollingStats<T, const N: usize> {
+    inner: Aggregated<T, N, Moments<T>>
+}
+
+impl<T, const N: usize> Default for RollingStats<T, N>
+    where
+        T: Real {
+
+    fn default() -> Self {
+        Self {
+            inner: SlidingWindow::new().with_aggregator(Moments::default())
+        }
+    }
+}
+
+impl<T, const N: usize> RollingStats<T, N>
+    where
+        T: Real {
+
+    /// Returns an empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a sample into the window and update the statistics.
+    ///
+    /// If the window is full, this method will remove and return the oldest sample.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of samples is not representable by `T`.
+    pub fn insert(&mut self, sample: T) -> Option<T> {
+        self.inner.insert(sample)
+    }
+
+    /// Removes all samples from the window and resets the statistics.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Returns the underlying window.
+    pub fn window(&self) -> &SlidingWindow<T, N> {
+        self.inner.window()
+    }
+
+    /// Returns the sum of the samples, or `None` if the window is empty.
+    pub fn sum(&self) -> Option<T> {
+        self.inner.aggregator().sum()
+    }
+
+    /// Returns the mean of the samples, or `None` if the window is empty.
+    pub fn mean(&self) -> Option<T> {
+        self.inner.aggregator().mean()
+    }
+
+    /// Returns the population variance of the samples, or `None` if the window is empty.
+    pub fn variance(&self) -> Option<T> {
+        self.inner.aggregator().variance()
+    }
+
+    /// Returns the sample variance of the samples, or `None` if the window holds fewer than
+    /// two samples.
+    pub fn sample_variance(&self) -> Option<T> {
+        self.inner.aggregator().sample_variance()
+    }
+
+    /// Returns the population standard deviation of the samples, or `None` if the window is
+    /// empty.
+    pub fn std_dev(&self) -> Option<T> {
+        self.inner.aggregator().std_dev()
+    }
+}
+
+/// A sliding window that keeps track of the sum of squares and root mean square of its contents.
+///
+/// The sum of squares is updated in O(1) on every insertion.
+pub struct RollingRms<T, const N: usize> {
+    inner: Aggregated<T, N, SumOfSquares<T>>
+}
+
+impl<T, const N: usize> Default for RollingRms<T, N>
+    where
+        T: Sample {
+
+    fn default() -> Self {
+        Self {
+            inner: SlidingWindow::new().with_aggregator(SumOfSquares::default())
+        }
+    }
+}
+
+impl<T, const N: usize> RollingRms<T, N>
+    where
+        T: Sample {
+
+    /// Returns an empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a sample into the window and update the sum of squares.
+    ///
+    /// If the window is full, this method will remove and return the oldest sample.
+    pub fn insert(&mut self, sample: T) -> Option<T> {
+        self.inner.insert(sample)
+    }
+
+    /// Removes all samples from the window and resets the sum of squares.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Returns the underlying window.
+    pub fn window(&self) -> &SlidingWindow<T, N> {
+        self.inner.window()
+    }
+
+    /// Returns the sum of the squared samples.
+    pub fn sum_of_squares(&self) -> T {
+        self.inner.aggregator().sum_of_squares()
+    }
+
+    /// Returns the root mean square of the samples, or `None` if the window is empty or the number
+    /// of samples is not representable by `T`.
+    pub fn rms(&self) -> Option<T> {
+        self.inner.aggregator().rms()
+    }
+}
+
+/// Weights used by [`WeightedWindow`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Weighting<T> {
+    /// The oldest sample has a weight of 1, every newer sample has a weight one larger than the
+    /// previous one.
+    Linear,
+
+    /// The newest sample has a weight of 1, every older sample is weighted by the given decay
+    /// factor relative to the next newer one.
+    Exponential(T)
+}
+
+/// A sliding window that keeps track of the weighted moving average of its contents.
+///
+/// The average is updated in O(1) on every insertion.
+pub struct WeightedWindow<T, const N: usize> {
+    window: SlidingWindow<T, N>,
+    weighting: Weighting<T>,
+    sum: T,
+    weighted_sum: T,
+    total_weight: T,
+    oldest_weight: T
+}
+
+impl<T, const N: usize> WeightedWindow<T, N>
+    where
+        T: Real {
+
+    /// Returns an empty object that uses the given weights.
+    ///
+    /// Fractional decay factors can not be represented by integers, so use
+    /// [`linear`](WeightedWindow::linear) for those:
+    ///
+    /// ```compile_fail
+    /// use sliding_window::stats::{WeightedWindow, Weighting};
+    ///
+    /// let window: WeightedWindow<u16, 4> = WeightedWindow::new(Weighting::Exponential(0));
+    /// ```
+    pub fn new(weighting: Weighting<T>) -> Self {
+        Self::with_weighting(weighting)
+    }
+}
+
+impl<T, const N: usize> WeightedWindow<T, N>
+    where
+        T: Sample {
+
+    /// Returns an empty object that uses linear weights.
+    pub fn linear() -> Self {
+        Self::with_weighting(Weighting::Linear)
+    }
+
+    fn with_weighting(weighting: Weighting<T>) -> Self {
+        Self {
+            window: SlidingWindow::new(),
+            weighting,
+            sum: T::ZERO,
+            weighted_sum: T::ZERO,
+            total_weight: T::ZERO,
+            oldest_weight: count_as(1)
+        }
+    }
+
+    /// Insert a sample into the window and update the weighted average.
+    ///
+    /// If the window is full, this method will remove and return the oldest sample.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total of the linear weights is not representable by `T`.
+    pub fn insert(&mut self, sample: T) -> Option<T> {
+        let old = self.window.insert(sample);
+
+        match self.weighting {
+            Weighting::Linear => {
+                let count = self.window.count();
+                let n: T = count_as(count);
+
+                self.weighted_sum = match old {
+                    None => self.weighted_sum + n * sample,
+                    Some(_) => self.weighted_sum - self.sum + n * sample
+                };
+                self.total_weight = count_as(count * (count + 1) / 2);
+            }
+            Weighting::Exponential(decay) => {
+                self.weighted_sum = match old {
+                    None => {
+                        self.total_weight = self.total_weight + self.oldest_weight;
+                        self.oldest_weight = self.oldest_weight * decay;
+                        decay * self.weighted_sum + sample
+                    }
+                    Some(old) => decay * self.weighted_sum + sample - self.oldest_weight * old
+                };
+            }
+        }
+
+        self.sum = match old {
+            None => self.sum + sample,
+            Some(old) => self.sum + sample - old
+        };
+
+        old
+    }
+
+    /// Removes all samples from the window and resets the weighted average.
+    pub fn clear(&mut self) {
+        *self = Self::with_weighting(self.weighting);
+    }
+
+    /// Returns the underlying window.
+    pub fn window(&self) -> &SlidingWindow<T, N> {
+        &self.window
+    }
+
+    /// Returns the weights used by the window.
+    pub fn weighting(&self) -> Weighting<T> {
+        self.weighting
+    }
+
+    /// Returns the weighted average of the samples, or `None` if the window is empty.
+    pub fn mean(&self) -> Option<T> {
+        if self.window.count() == 0 {
+            None
+        } else {
+            Some(self.weighted_sum / self.total_weight)
+        }
+    }
+}
+
+/// A pair of sliding windows that hold raw samples and their exponentially smoothed values.
+///
+/// Smoothing is done by a single-pole IIR filter: every smoothed value is
+/// `previous + alpha * (sample - previous)`, and the first one is the first sample itself.
+///
+/// The smoothing factor is fractional, so integer types can not be smoothed:
+///
+/// ```compile_fail
+/// use sliding_window::stats::Smoothed;
+///
+/// let window: Smoothed<u16, 4> = Smoothed::new(1);
+/// ```
+///
+/// ```compile_fail
+/// use sliding_window::stats::Smoothed;
+///
+/// let window: Smoothed<i32, 4> = Smoothed::new(1);
+/// ```
+pub struct Smoothed<T, const N: usize> {
+    raw: SlidingWindow<T, N>,
+    smoothed: SlidingWindow<T, N>,
+    alpha: T,
+    value: Option<T>
+}
+
+impl<T, const N: usize> Smoothed<T, N>
+    where
+        T: Real {
+
+    /// Returns an empty object that smooths using the factor `alpha`.
+    ///
+    /// `alpha` is expected to be between 0 and 1, larger values follow the input more closely.
+    pub fn new(alpha: T) -> Self {
+        Self {
+            raw: SlidingWindow::new(),
+            smoothed: SlidingWindow::new(),
+            alpha,
+            value: None
+        }
+    }
+
+    /// Insert a sample into the window, and return the new smoothed value.
+    ///
+    /// If the windows are full, the oldest raw and smoothed values are removed.
+    pub fn insert(&mut self, sample: T) -> T {
+        let value = match self.value {
+            None => sample,
+            Some(previous) => previous + self.alpha * (sample - previous)
+        };
+
+        self.raw.insert(sample);
+        self.smoothed.insert(value);
+        self.value = Some(value);
+
+        value
+    }
+
+    /// Removes all samples and resets the filter.
+    ///
+    /// The filter state is reset too, so the next sample is taken as the first smoothed value.
+    pub fn clear(&mut self) {
+        self.raw.clear();
+        self.smoothed.clear();
+        self.value = None;
+    }
+
+    /// Returns the latest smoothed value, or `None` if no sample has been inserted.
+    pub fn value(&self) -> Option<T> {
+        self.value
+    }
+
+    /// Returns the smoothing factor.
+    pub fn alpha(&self) -> T {
+        self.alpha
+    }
+
+    /// Returns the window of raw samples.
+    pub fn raw(&self) -> &SlidingWindow<T, N> {
+        &self.raw
+    }
+
+    /// Returns the window of smoothed values.
+    pub fn smoothed(&self) -> &SlidingWindow<T, N> {
+        &self.smoothed
+    }
+}
+
+/// Fixed capacity double-ended queue of insertion sequence numbers.
+struct SequenceDeque<const N: usize> {
+    items: [usize; N],
+    head: usize,
+    len: usize
+}
+
+impl<const N: usize> SequenceDeque<N> {
+    fn new() -> Self {
+        Self {
+            items: [0; N],
+            head: 0,
+            len: 0
+        }
+    }
+
+    fn front(&self) -> Option<usize> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(self.items[self.head])
+        }
+    }
+
+    fn back(&self) -> Option<usize> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(self.items[self.head.wrapping_add_limited(self.len - 1, N)])
+        }
+    }
+
+    fn push_back(&mut self, seq: usize) {
+        debug_assert!(self.len < N);
+        self.items[self.head.wrapping_add_limited(self.len, N)] = seq;
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) {
+        self.head = self.head.wrapping_add1_limited(N);
+        self.len -= 1;
+    }
+
+    fn pop_back(&mut self) {
+        self.len -= 1;
+    }
+
+    fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+}
+
+/// A sliding window that keeps track of its smallest and largest elements.
+///
+/// The extrema are tracked using monotonic queues, so [`min`] and [`max`] are O(1) and
+/// insertion is amortized O(1).
+///
+/// [`min`]: MinMaxWindow::min
+/// [`max`]: MinMaxWindow::max
+pub struct MinMaxWindow<T, const N: usize> {
+    window: SlidingWindow<T, N>,
+    min: SequenceDeque<N>,
+    max: SequenceDeque<N>,
+    next_seq: usize
+}
+
+impl<T, const N: usize> Default for MinMaxWindow<T, N>
+    where
+        T: PartialOrd {
+
+    fn default() -> Self {
+        Self {
+            window: SlidingWindow::new(),
+            min: SequenceDeque::new(),
+            max: SequenceDeque::new(),
+            next_seq: 0
+        }
+    }
+}
+
+impl<T, const N: usize> MinMaxWindow<T, N>
+    where
+        T: PartialOrd {
+
+    /// Returns an empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert an element into the window and update the extrema.
+    ///
+    /// If the window is full, this method will remove and return the oldest element.
+    pub fn insert(&mut self, t: T) -> Option<T> {
+        if self.window.is_full() {
+            let evicted = self.next_seq.wrapping_sub(N);
+            if self.min.front() == Some(evicted) {
+                self.min.pop_front();
+            }
+            if self.max.front() == Some(evicted) {
+                self.max.pop_front();
+            }
+        }
+
+        let old = self.window.insert(t);
+        let seq = self.next_seq;
+        self.next_seq = seq.wrapping_add(1);
+
+        while let Some(back) = self.min.back() {
+            if self.get(back) < self.get(seq) {
+                break;
+            }
+            self.min.pop_back();
+        }
+        self.min.push_back(seq);
+
+        while let Some(back) = self.max.back() {
+            if self.get(back) > self.get(seq) {
+                break;
+            }
+            self.max.pop_back();
+        }
+        self.max.push_back(seq);
+
+        old
+    }
+
+    /// Removes all elements from the window.
+    pub fn clear(&mut self) {
+        self.window.clear();
+        self.min.clear();
+        self.max.clear();
+    }
+
+    /// Returns the underlying window.
+    pub fn window(&self) -> &SlidingWindow<T, N> {
+        &self.window
+    }
+
+    /// Returns the smallest element, or `None` if the window is empty.
+    pub fn min(&self) -> Option<&T> {
+        self.min.front().map(|seq| self.get(seq))
+    }
+
+    /// Returns the largest element, or `None` if the window is empty.
+    pub fn max(&self) -> Option<&T> {
+        self.max.front().map(|seq| self.get(seq))
+    }
+
+    fn get(&self, seq: usize) -> &T {
+        let oldest = self.next_seq.wrapping_sub(self.window.count());
+        &self.window[seq.wrapping_sub(oldest)]
+    }
+}
+
+/// A sliding window that keeps an ordered index of its contents to answer median and percentile
+/// queries.
+///
+/// Insertion is O(N), queries are O(1). No copies of the elements are made.
+pub struct MedianWindow<T, const N: usize> {
+    window: SlidingWindow<T, N>,
+    sorted: [usize; N],
+    next_seq: usize
+}
+
+impl<T, const N: usize> Default for MedianWindow<T, N>
+    where
+        T: PartialOrd {
+
+    fn default() -> Self {
+        Self {
+            window: SlidingWindow::new(),
+            sorted: [0; N],
+            next_seq: 0
+        }
+    }
+}
+
+impl<T, const N: usize> MedianWindow<T, N>
+    where
+        T: PartialOrd {
+
+    /// Returns an empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert an element into the window and update the ordered index.
+    ///
+    /// If the window is full, this method will remove and return the oldest element.
+    pub fn insert(&mut self, t: T) -> Option<T> {
+        let mut len = self.window.count();
+        if self.window.is_full() {
+            let evicted = self.next_seq.wrapping_sub(N);
+            let pos = self.sorted.iter().position(|&seq| seq == evicted).unwrap();
+
+            self.sorted.copy_within(pos + 1.., pos);
+            len -= 1;
+        }
+
+        let old = self.window.insert(t);
+        let seq = self.next_seq;
+        self.next_seq = seq.wrapping_add(1);
+
+        let pos = {
+            let new = self.get(seq);
+            self.sorted[..len].partition_point(|&other| self.get(other) <= new)
+        };
+        self.sorted.copy_within(pos..len, pos + 1);
+        self.sorted[pos] = seq;
+
+        old
+    }
+
+    /// Removes all elements from the window.
+    pub fn clear(&mut self) {
+        self.window.clear();
+    }
+
+    /// Returns the underlying window.
+    pub fn window(&self) -> &SlidingWindow<T, N> {
+        &self.window
+    }
+
+    /// Returns the median element, or `None` if the window is empty.
+    ///
+    /// For an even number of elements, the lower of the two middle elements is returned.
+    pub fn median(&self) -> Option<&T> {
+        self.percentile(50.0)
+    }
+
+    /// Returns the element at the `p`-th percentile using the nearest-rank method, or `None` if
+    /// the window is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not between 0 and 100.
+    pub fn percentile(&self, p: f32) -> Option<&T> {
+        assert!((0.0..=100.0).contains(&p), "Percentile must be between 0 and 100");
+
+        let len = self.window.count();
+        if len == 0 {
+            return None;
+        }
+
+        let rank = libm::ceilf(p / 100.0 * len as f32) as usize;
+        let idx = rank.clamp(1, len) - 1;
+
+        Some(self.get(self.sorted[idx]))
+    }
+
+    fn get(&self, seq: usize) -> &T {
+        let oldest = self.next_seq.wrapping_sub(self.window.count());
+        &self.window[seq.wrapping_sub(oldest)]
+    }
+}
+
+impl<T, const N: usize> SlidingWindow<T, N>
+    where
+        T: Sample {
+
+    /// Returns the mean of the elements after dropping the `discard` smallest and `discard`
+    /// largest ones.
+    ///
+    /// Elements that can not be compared (e.g. `NaN`) count as the largest ones. Returns `None` if
+    /// no elements remain after trimming, or if their number is not representable by `T`.
+    pub fn trimmed_mean(&self, discard: usize) -> Option<T> {
+        let kept = self.count().checked_sub(discard.checked_mul(2)?).filter(|&kept| kept > 0)?;
+
+        let sum = self.iter_sorted()
+            .skip(discard)
+            .take(kept)
+            .fold(T::ZERO, |sum, &x| sum + x);
+
+        Some(sum / T::from_usize(kept)?)
+    }
+
+    /// Returns the mean of the elements after replacing the `discard` smallest and `discard`
+    /// largest ones with the nearest remaining element.
+    ///
+    /// Elements that can not be compared (e.g. `NaN`) count as the largest ones. Returns `None` if
+    /// no elements remain after trimming, or if their number is not representable by `T`.
+    pub fn winsorized_mean(&self, discard: usize) -> Option<T> {
+        let count = self.count();
+        let kept = count.checked_sub(discard.checked_mul(2)?).filter(|&kept| kept > 0)?;
+
+        let low = *self.nth_smallest(discard)?;
+        let high = *self.nth_smallest(discard + kept - 1)?;
+        let sum = self.iter()
+            .map(|&x| match (compare(&x, &low), compare(&x, &high)) {
+                (Ordering::Less, _) => low,
+                (_, Ordering::Greater) => high,
+                _ => x
+            })
+            .fold(T::ZERO, |sum, x| sum + x);
+
+        Some(sum / T::from_usize(count)?)
+    }
+}
+
+/// Returns the sum of the elements of `slice`.
+fn sum<T>(slice: &[T]) -> T
+    where
+        T: Sample {
+    slice.iter().fold(T::ZERO, |sum, &x| sum + x)
+}
+
+impl<T, S> SlidingWindowBase<T, S>
+    where
+        S: Storage<T>,
+        T: Sample {
+
+    /// Returns the sum of the elements.
+    ///
+    /// The sum of an empty window is zero. The elements are summed as two contiguous slices,
+    /// which is faster than summing the values returned by [`iter`](SlidingWindowBase::iter).
+    /// Integer overflow is handled like the `+` operator does, see
+    /// [`sum_with`](SlidingWindowBase::sum_with) for other options.
+    pub fn sum(&self) -> T {
+        let (older, newer) = self.as_slices();
+
+        sum(older) + sum(newer)
+    }
+
+    /// Returns the mean of the elements, or `None` if the window is empty or the number of
+    /// elements is not representable by `T`.
+    pub fn mean(&self) -> Option<T> {
+        if self.count() == 0 {
+            return None;
+        }
+
+        Some(self.sum() / T::from_usize(self.count())?)
+    }
+}
+
+impl<T, S> SlidingWindowBase<T, S>
+    where
+        S: Storage<T>,
+        T: PartialOrd {
+
+    /// Returns the smallest element, or `None` if the window is empty.
+    ///
+    /// Elements that can not be compared (e.g. `NaN`) are treated as equal to every other element.
+    pub fn min(&self) -> Option<&T> {
+        let (older, newer) = self.as_slices();
+
+        older.iter().chain(newer).min_by(crate::compare)
+    }
+
+    /// Returns the largest element, or `None` if the window is empty.
+    ///
+    /// Elements that can not be compared (e.g. `NaN`) are treated as equal to every other element.
+    pub fn max(&self) -> Option<&T> {
+        let (older, newer) = self.as_slices();
+
+        older.iter().chain(newer).max_by(crate::compare)
+    }
+}
+
+impl<T, S> SlidingWindowBase<T, S>
+    where
+        S: Storage<T>,
+        T: Accumulate {
+
+    /// Returns the sum of the elements, handling overflow according to `policy`.
+    ///
+    /// Returns `None` if the sum overflows and `policy` is [`Overflow::Checked`]. The sum of an
+    /// empty window is zero.
+    pub fn sum_with(&self, policy: Overflow) -> Option<T> {
+        self.iter().try_fold(T::ZERO, |sum, &x| sum.add_with(x, policy))
+    }
+
+    /// Returns the mean of the elements, handling overflow of the sum according to `policy`.
+    ///
+    /// The mean of the elements always fits the type, so with [`Overflow::Saturating`] the sum is
+    /// computed in a 128-bit integer instead of being clamped, see [`Accumulate::wide_mean`]. With
+    /// the other policies, `None` is returned if the sum overflows and `policy` is
+    /// [`Overflow::Checked`], or if the number of elements is not representable by `T`.
+    ///
+    /// Returns `None` if the window is empty. The result is rounded towards zero.
+    pub fn mean_with(&self, policy: Overflow) -> Option<T> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+
+        match policy {
+            Overflow::Saturating => T::wide_mean(self.iter().copied(), count),
+            _ => Some(self.sum_with(policy)? / T::from_usize(count)?)
+        }
+    }
+
+    /// Returns the sum of the elements, or `None` if it overflows.
+    pub fn checked_sum(&self) -> Option<T> {
+        self.sum_with(Overflow::Checked)
+    }
+
+    /// Returns the sum of the elements, clamped to the range of the type.
+    pub fn saturating_sum(&self) -> T {
+        self.sum_with(Overflow::Saturating).unwrap()
+    }
+
+    /// Returns the mean of the elements, or `None` if the window is empty, the sum overflows or
+    /// the number of elements is not representable by `T`.
+    pub fn checked_mean(&self) -> Option<T> {
+        self.mean_with(Overflow::Checked)
+    }
+
+    /// Returns the mean of the elements without overflowing, or `None` if the window is empty.
+    ///
+    /// The sum is computed in a 128-bit integer, so for 128-bit elements, `None` is also returned
+    /// if that overflows.
+    pub fn saturating_mean(&self) -> Option<T> {
+        self.mean_with(Overflow::Saturating)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(expected: f64, actual: Option<f64>) {
+        let actual = actual.unwrap();
+        assert!((expected - actual).abs() < 1e-9, "{} != {}", expected, actual);
+    }
+
+    #[test]
+    fn empty() {
+        let stats: RollingStats<f64, 4> = RollingStats::new();
+
+        assert_eq!(None, stats.sum());
+        assert_eq!(None, stats.mean());
+        assert_eq!(None, stats.variance());
+        assert_eq!(None, stats.sample_variance());
+        assert_eq!(None, stats.std_dev());
+    }
+
+    #[test]
+    fn integer_samples() {
+        let mut window: SlidingWindow<i32, 4> = SlidingWindow::new();
+        for &sample in [9, 1, -4, 7, 2].iter() {
+            window.insert(sample);
+        }
+
+        // [1, -4, 7, 2]
+        assert_eq!(6, window.sum());
+        assert_eq!(Some(1), window.mean());
+        assert_eq!(Some(1), window.trimmed_mean(1));
+
+        let mut rms: RollingRms<u16, 4> = RollingRms::new();
+        for &sample in [9, 1, 3, 3, 3].iter() {
+            rms.insert(sample);
+        }
+
+        assert_eq!(28, rms.sum_of_squares());
+        assert_eq!(Some(2), rms.rms());
+
+        let mut weighted: WeightedWindow<u32, 3> = WeightedWindow::linear();
+        for &sample in [5, 3, 6, 9].iter() {
+            weighted.insert(sample);
+        }
+
+        // (3 + 2 * 6 + 3 * 9) / 6
+        assert_eq!(Some(7), weighted.mean());
+    }
+
+    #[test]
+    fn count_not_representable() {
+        let mut window: SlidingWindow<u8, 300> = SlidingWindow::new();
+        let mut rms: RollingRms<u8, 300> = RollingRms::new();
+        for _ in 0..300 {
+            window.insert(0);
+            rms.insert(0);
+        }
+
+        assert_eq!(None, window.mean());
+        assert_eq!(None, window.trimmed_mean(0));
+        assert_eq!(Some(0), window.trimmed_mean(50));
+        assert_eq!(None, rms.rms());
+    }
+
+    #[test]
+    fn sum_is_accumulated() {
+        let mut stats: RollingStats<f64, 4> = RollingStats::new();
+
+        stats.insert(0.1);
+        stats.insert(0.2);
+        stats.insert(0.7);
+
+        assert_eq!(Some(0.1 + 0.2 + 0.7), stats.sum());
+    }
+
+    #[test]
+    fn matches_recomputed_values() {
+        let mut stats: RollingStats<f64, 4> = RollingStats::new();
+
+        for &sample in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0, -3.0, 0.5].iter() {
+            stats.insert(sample);
+
+            let count = stats.window().count() as f64;
+            let sum: f64 = stats.window().iter().sum();
+            let mean = sum / count;
+            let variance = stats.window().iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / count;
+
+            assert_close(sum, stats.sum());
+            assert_close(mean, stats.mean());
+            assert_close(variance, stats.variance());
+            assert_close(variance.sqrt(), stats.std_dev());
+        }
+
+        stats.clear();
+        assert_eq!(None, stats.mean());
+    }
+
+    #[test]
+    fn rms() {
+        let mut rms: RollingRms<f32, 4> = RollingRms::new();
+
+        assert_eq!(None, rms.rms());
+        assert_eq!(0.0, rms.sum_of_squares());
+
+        rms.insert(3.0);
+        rms.insert(-3.0);
+        assert_eq!(18.0, rms.sum_of_squares());
+        assert_eq!(Some(3.0), rms.rms());
+
+        rms.insert(1.0);
+        rms.insert(1.0);
+        rms.insert(-1.0);
+        rms.insert(1.0);
+        assert_eq!(4.0, rms.sum_of_squares());
+        assert_eq!(Some(1.0), rms.rms());
+
+        rms.clear();
+        assert_eq!(None, rms.rms());
+    }
+
+    #[test]
+    fn linear_weights() {
+        let mut window: WeightedWindow<f64, 3> = WeightedWindow::new(Weighting::Linear);
+
+        assert_eq!(None, window.mean());
+
+        window.insert(3.0);
+        assert_close(3.0, window.mean());
+
+        window.insert(6.0);
+        assert_close((3.0 + 2.0 * 6.0) / 3.0, window.mean());
+
+        window.insert(0.0);
+        window.insert(3.0);
+        window.insert(9.0);
+        assert_close((0.0 + 2.0 * 3.0 + 3.0 * 9.0) / 6.0, window.mean());
+    }
+
+    #[test]
+    fn exponential_weights() {
+        let mut window: WeightedWindow<f64, 3> = WeightedWindow::new(Weighting::Exponential(0.5));
+
+        window.insert(4.0);
+        assert_close(4.0, window.mean());
+
+        window.insert(8.0);
+        assert_close((0.5 * 4.0 + 8.0) / 1.5, window.mean());
+
+        window.insert(2.0);
+        window.insert(4.0);
+        window.insert(12.0);
+        assert_close((0.25 * 2.0 + 0.5 * 4.0 + 12.0) / 1.75, window.mean());
+
+        window.clear();
+        assert_eq!(None, window.mean());
+    }
+
+    #[test]
+    fn smoothed() {
+        let mut window: Smoothed<f32, 3> = Smoothed::new(0.5);
+
+        assert_eq!(None, window.value());
+        assert_eq!(4.0, window.insert(4.0));
+        assert_eq!(6.0, window.insert(8.0));
+        assert_eq!(4.0, window.insert(2.0));
+        assert_eq!(6.0, window.insert(8.0));
+
+        assert_eq!(vec![8.0, 2.0, 8.0], window.raw().iter().copied().collect::<Vec<_>>());
+        assert_eq!(vec![6.0, 4.0, 6.0], window.smoothed().iter().copied().collect::<Vec<_>>());
+
+        window.clear();
+        assert_eq!(None, window.value());
+        assert_eq!(1.0, window.insert(1.0));
+    }
+
+    #[test]
+    #[cfg(feature = "fixed")]
+    fn smoothed_fixed_point() {
+        use fixed::types::I16F16;
+
+        let mut window: Smoothed<I16F16, 3> = Smoothed::new(I16F16::from_num(0.25));
+
+        window.insert(I16F16::from_num(10));
+        assert_eq!(I16F16::from_num(8), window.insert(I16F16::from_num(2)));
+        assert_eq!(I16F16::from_num(6), window.insert(I16F16::from_num(0)));
+    }
+
+    #[test]
+    fn histogram() {
+        let histogram = Histogram::<_, 3>::new(|x: &u8| *x as usize);
+        let mut sw = SlidingWindow::<u8, 4>::new().with_aggregator(histogram);
+
+        assert_eq!(&[0, 0, 0], sw.aggregator().bins());
+
+        for &sample in [0, 1, 1, 7, 2, 0].iter() {
+            sw.insert(sample);
+        }
+
+        assert_eq!(&[1, 1, 2], sw.aggregator().bins());
+        assert_eq!(4, sw.aggregator().total());
+
+        sw.clear();
+        assert_eq!(0, sw.aggregator().total());
+    }
+
+    #[test]
+    fn min_max() {
+        let mut window: MinMaxWindow<i32, 3> = MinMaxWindow::new();
+
+        assert_eq!(None, window.min());
+        assert_eq!(None, window.max());
+
+        for &sample in [5, 1, 4, 4, 8, 2, 2, 2, 9, -1, 3, 3].iter() {
+            window.insert(sample);
+
+            assert_eq!(window.window().iter().min(), window.min());
+            assert_eq!(window.window().iter().max(), window.max());
+        }
+
+        window.clear();
+        assert_eq!(None, window.min());
+        assert_eq!(None, window.max());
+    }
+
+    #[test]
+    fn median() {
+        let mut window: MedianWindow<i32, 4> = MedianWindow::new();
+
+        assert_eq!(None, window.median());
+
+        for &sample in [5, 1, 4, 4, 8, 2, 2, 2, 9, -1, 3, 3, 7].iter() {
+            window.insert(sample);
+
+            let mut sorted = window.window().iter().copied().collect::<Vec<_>>();
+            sorted.sort_unstable();
+
+            assert_eq!(Some(&sorted[(sorted.len() - 1) / 2]), window.median());
+            assert_eq!(Some(&sorted[0]), window.percentile(0.0));
+            assert_eq!(sorted.last(), window.percentile(100.0));
+        }
+    }
+
+    #[test]
+    fn trimmed_mean() {
+        let mut window: SlidingWindow<f32, 6> = SlidingWindow::new();
+
+        assert_eq!(None, window.trimmed_mean(0));
+
+        for &sample in [5.0, 1.0, 4.0, 100.0, 6.0, -50.0].iter() {
+            window.insert(sample);
+        }
+
+        assert_eq!(Some(11.0), window.trimmed_mean(0));
+        assert_eq!(Some(4.0), window.trimmed_mean(1));
+        assert_eq!(Some(4.5), window.trimmed_mean(2));
+        assert_eq!(None, window.trimmed_mean(3));
+
+        // [1, 1, 4, 5, 6, 6]
+        assert_eq!(Some(23.0 / 6.0), window.winsorized_mean(1));
+        assert_eq!(None, window.winsorized_mean(usize::MAX));
+    }
+
+    #[test]
+    fn trimmed_mean_nan() {
+        let mut window: SlidingWindow<f32, 6> = SlidingWindow::new();
+
+        for &sample in [5.0, 1.0, 4.0, f32::NAN, 6.0, -50.0].iter() {
+            window.insert(sample);
+        }
+
+        assert_eq!(Some(4.0), window.trimmed_mean(1));
+
+        // [5, 1, 4, 6, 6, 1]
+        assert_eq!(Some(23.0 / 6.0), window.winsorized_mean(1));
+    }
+
+    #[test]
+    fn reductions() {
+        let mut window: SlidingWindow<f32, 4> = SlidingWindow::new();
+
+        assert_eq!(0.0, window.sum());
+        assert_eq!(None, window.mean());
+        assert_eq!(None, window.min());
+        assert_eq!(None, window.max());
+
+        for &sample in [9.0, -2.0, 6.0, 1.0, 3.0].iter() {
+            window.insert(sample);
+        }
+
+        // [-2, 6, 1, 3], wrapped around the end of the storage
+        assert_eq!(8.0, window.sum());
+        assert_eq!(Some(2.0), window.mean());
+        assert_eq!(Some(&-2.0), window.min());
+        assert_eq!(Some(&6.0), window.max());
+    }
+
+    #[test]
+    fn overflow_policy() {
+        let mut window: SlidingWindow<u16, 4> = SlidingWindow::new();
+
+        assert_eq!(Some(0), window.checked_sum());
+        assert_eq!(None, window.saturating_mean());
+
+        window.insert(40_000);
+        window.insert(20_000);
+        assert_eq!(Some(60_000), window.checked_sum());
+        assert_eq!(Some(30_000), window.checked_mean());
+
+        window.insert(10_000);
+        assert_eq!(None, window.checked_sum());
+        assert_eq!(None, window.checked_mean());
+        assert_eq!(u16::MAX, window.saturating_sum());
+        assert_eq!(Some(23_333), window.saturating_mean());
+        assert_eq!(Some(4_464), window.sum_with(Overflow::Wrapping));
+    }
+
+    #[test]
+    fn overflow_policy_count_not_representable() {
+        let mut window: SlidingWindow<u8, 300> = SlidingWindow::new();
+
+        for _ in 0..256 {
+            window.insert(0);
+        }
+        assert_eq!(Some(0), window.checked_sum());
+        assert_eq!(None, window.checked_mean());
+        assert_eq!(Some(0), window.saturating_mean());
+
+        for _ in 0..44 {
+            window.insert(1);
+        }
+        assert_eq!(Some(44), window.checked_sum());
+        assert_eq!(None, window.checked_mean());
+        assert_eq!(None, window.mean_with(Overflow::Wrapping));
+        assert_eq!(Some(0), window.saturating_mean());
+
+        window.clear();
+        for _ in 0..300 {
+            window.insert(200);
+        }
+        assert_eq!(None, window.checked_mean());
+        assert_eq!(Some(200), window.saturating_mean());
+    }
+
+    #[test]
+    fn percentile() {
+        let mut window: MedianWindow<u32, 10> = MedianWindow::new();
+
+        for sample in (1..=10).rev() {
+            window.insert(sample * 10);
+        }
+
+        assert_eq!(Some(&10), window.percentile(5.0));
+        assert_eq!(Some(&30), window.percentile(30.0));
+        assert_eq!(Some(&40), window.percentile(31.0));
+        assert_eq!(Some(&90), window.percentile(90.0));
+    }
+}
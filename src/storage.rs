@@ -0,0 +1,201 @@
+//! Memory that holds the elements of a sliding window.
+//!
+//! [`SlidingWindow`](crate::SlidingWindow) stores its elements in an array. With the `alloc`
+//! feature, [`DynSlidingWindow`](crate::DynSlidingWindow) stores them in a boxed slice, so its
+//! capacity can be chosen at runtime. [`SlidingWindowRef`](crate::SlidingWindowRef) works on a
+//! buffer provided by the caller, which can be placed in a specific memory region.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use core::mem::MaybeUninit;
+//! use sliding_window::SlidingWindowRef;
+//!
+//! let mut buffer = [MaybeUninit::uninit(); 3];
+//! let mut window = SlidingWindowRef::new(&mut buffer);
+//!
+//! window.insert(1);
+//! window.insert(2);
+//!
+//! assert_eq!(2, window.count());
+//! assert_eq!(3, window.capacity());
+//! ```
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use sliding_window::DynSlidingWindow;
+//!
+//! let mut window: DynSlidingWindow<u32> = DynSlidingWindow::new(3);
+//!
+//! window.insert(1);
+//! window.insert(2);
+//! window.insert(3);
+//!
+//! assert_eq!(Some(1), window.insert(4));
+//! assert_eq!(3, window.capacity());
+//! # }
+//! ```
+
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+use crate::SlidingWindowRef;
+
+/// Memory that holds the elements of a [`SlidingWindowBase`](crate::SlidingWindowBase).
+///
+/// # Safety
+///
+/// Both methods must return slices of the same length, and the length must not change while the
+/// storage is used by a window.
+pub unsafe trait Storage<IT> {
+    /// Returns the storage as a slice.
+    fn as_slice(&self) -> &[MaybeUninit<IT>];
+
+    /// Returns the storage as a mutable slice.
+    fn as_mut_slice(&mut self) -> &mut [MaybeUninit<IT>];
+}
+
+unsafe impl<IT, const N: usize> Storage<IT> for [MaybeUninit<IT>; N] {
+    fn as_slice(&self) -> &[MaybeUninit<IT>] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [MaybeUninit<IT>] {
+        self
+    }
+}
+
+unsafe impl<IT> Storage<IT> for &mut [MaybeUninit<IT>] {
+    fn as_slice(&self) -> &[MaybeUninit<IT>] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [MaybeUninit<IT>] {
+        self
+    }
+}
+
+impl<'a, IT> SlidingWindowRef<'a, IT> {
+    /// Returns an empty sliding window that stores its elements in `buffer`.
+    ///
+    /// The window can hold as many elements as the length of `buffer`. Elements still in the
+    /// window are dropped when the window is dropped, after which the buffer can be reused.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is empty.
+    pub fn new(buffer: &'a mut [MaybeUninit<IT>]) -> Self {
+        assert!(!buffer.is_empty(), "Capacity must be non-zero");
+
+        Self {
+            items: buffer,
+            read_idx: 0,
+            count: 0,
+            _marker: PhantomData
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::mem::MaybeUninit;
+
+    use crate::SlidingWindowRef;
+
+    #[test]
+    fn borrowed_storage() {
+        let mut buffer = [MaybeUninit::uninit(); 3];
+
+        let mut sw = SlidingWindowRef::new(&mut buffer[..]);
+        for i in 1..=5 {
+            sw.insert(i);
+        }
+        assert_eq!(vec![3, 4, 5], sw.iter().copied().collect::<Vec<_>>());
+        drop(sw);
+
+        // The buffer can be reused by another window
+        let mut sw = SlidingWindowRef::new(&mut buffer[..2]);
+        sw.insert(6);
+        assert_eq!(2, sw.capacity());
+        assert_eq!(6, sw[0]);
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod boxed {
+    use alloc::boxed::Box;
+    use core::marker::PhantomData;
+    use core::mem::MaybeUninit;
+
+    use crate::DynSlidingWindow;
+    use super::Storage;
+
+    unsafe impl<IT> Storage<IT> for Box<[MaybeUninit<IT>]> {
+        fn as_slice(&self) -> &[MaybeUninit<IT>] {
+            self
+        }
+
+        fn as_mut_slice(&mut self) -> &mut [MaybeUninit<IT>] {
+            self
+        }
+    }
+
+    impl<IT> DynSlidingWindow<IT> {
+        /// Returns an empty sliding window that can hold `capacity` elements.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `capacity` is 0.
+        pub fn new(capacity: usize) -> Self {
+            assert_ne!(capacity, 0, "Capacity must be non-zero");
+
+            Self {
+                items: (0..capacity).map(|_| MaybeUninit::uninit()).collect(),
+                read_idx: 0,
+                count: 0,
+                _marker: PhantomData
+            }
+        }
+    }
+
+    impl<IT> Clone for DynSlidingWindow<IT>
+        where
+            IT: Clone {
+
+        fn clone(&self) -> Self {
+            let mut window = Self::new(self.capacity());
+            for item in self.iter() {
+                window.insert(item.clone());
+            }
+
+            window
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::DynSlidingWindow;
+
+        #[test]
+        fn dyn_window() {
+            let mut sw: DynSlidingWindow<_> = DynSlidingWindow::new(3);
+
+            assert_eq!(3, sw.capacity());
+            for i in 1..=5 {
+                sw.insert(i);
+            }
+
+            assert!(sw.is_full());
+            assert_eq!(3, sw[0]);
+            assert_eq!(vec![3, 4, 5], sw.iter().copied().collect::<Vec<_>>());
+            assert_eq!(vec![5, 4, 3], sw.clone().iter_newest_first().copied().collect::<Vec<_>>());
+        }
+
+        #[test]
+        #[should_panic(expected = "Capacity must be non-zero")]
+        fn zero_capacity() {
+            let _: DynSlidingWindow<u8> = DynSlidingWindow::new(0);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Sliding window of time-stamped samples.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::timed::TimedWindow;
+//!
+//! // Keep at most 16 samples, but none that is older than 500 ms
+//! let mut window: TimedWindow<u16, u32, 16> = TimedWindow::new();
+//!
+//! window.insert(100, 7);
+//! window.insert(600, 9);
+//! window.insert(700, 8);
+//!
+//! let now = 1000;
+//! window.purge_older_than(now - 500);
+//!
+//! assert_eq!(2, window.count());
+//! assert_eq!(Some(&600), window.oldest_timestamp());
+//! ```
+
+use crate::{SlidingWindow, Iter};
+
+/// A sliding window that stores a timestamp with each sample.
+///
+/// Besides the size limit, samples can be removed based on their age. Timestamps are expected to
+/// be non-decreasing in the order of insertion.
+pub struct TimedWindow<T, Tick, const N: usize> {
+    window: SlidingWindow<(Tick, T), N>
+}
+
+impl<T, Tick, const N: usize> Default for TimedWindow<T, Tick, N> {
+    fn default() -> Self {
+        Self {
+            window: SlidingWindow::new()
+        }
+    }
+}
+
+impl<T, Tick, const N: usize> TimedWindow<T, Tick, N>
+    where
+        Tick: PartialOrd {
+
+    /// Returns an empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a sample with its timestamp into the window.
+    ///
+    /// If the window is full, this method will remove and return the oldest sample.
+    pub fn insert(&mut self, timestamp: Tick, t: T) -> Option<(Tick, T)> {
+        self.window.insert((timestamp, t))
+    }
+
+    /// Removes every sample whose timestamp is earlier than `deadline`.
+    ///
+    /// Returns the number of removed samples.
+    pub fn purge_older_than(&mut self, deadline: Tick) -> usize {
+        let mut removed = 0;
+        while self.oldest_timestamp().is_some_and(|timestamp| *timestamp < deadline) {
+            self.window.pop_oldest();
+            removed += 1;
+        }
+
+        removed
+    }
+
+    /// Removes all samples from the window.
+    pub fn clear(&mut self) {
+        self.window.clear();
+    }
+
+    /// Returns the timestamp of the oldest sample, or `None` if the window is empty.
+    pub fn oldest_timestamp(&self) -> Option<&Tick> {
+        self.window.iter().next().map(|(timestamp, _)| timestamp)
+    }
+
+    /// Returns the timestamp of the newest sample, or `None` if the window is empty.
+    pub fn newest_timestamp(&self) -> Option<&Tick> {
+        self.window.iter_newest_first().next().map(|(timestamp, _)| timestamp)
+    }
+
+    /// Returns the number of samples stored in the window.
+    pub fn count(&self) -> usize {
+        self.window.count()
+    }
+
+    /// Returns an iterator over the `(timestamp, sample)` pairs, starting with the oldest.
+    pub fn iter(&self) -> Iter<'_, (Tick, T)> {
+        self.window.iter()
+    }
+
+    /// Returns the underlying window.
+    pub fn window(&self) -> &SlidingWindow<(Tick, T), N> {
+        &self.window
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn purge() {
+        let mut window: TimedWindow<char, u32, 3> = TimedWindow::new();
+
+        assert_eq!(0, window.purge_older_than(10));
+
+        window.insert(1, 'a');
+        window.insert(2, 'b');
+        window.insert(3, 'c');
+        assert_eq!(Some((1, 'a')), window.insert(5, 'd'));
+
+        assert_eq!(0, window.purge_older_than(2));
+        assert_eq!(1, window.purge_older_than(3));
+        assert!(window.iter().eq([(3, 'c'), (5, 'd')].iter()));
+
+        // Samples inserted after a purge are placed after the remaining ones
+        window.insert(6, 'e');
+        assert!(window.iter().eq([(3, 'c'), (5, 'd'), (6, 'e')].iter()));
+        assert_eq!(Some(&3), window.oldest_timestamp());
+        assert_eq!(Some(&6), window.newest_timestamp());
+
+        assert_eq!(3, window.purge_older_than(7));
+        assert_eq!(None, window.oldest_timestamp());
+    }
+}
@@ -0,0 +1,235 @@
+//! Ordered views into a part of a sliding window.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sliding_window::SlidingWindow;
+//!
+//! let mut window: SlidingWindow<u32, 4> = SlidingWindow::new();
+//! for i in 1..=6 {
+//!     window.insert(i);
+//! }
+//!
+//! // Everything but the newest sample
+//! let view = window.view(..window.count() - 1);
+//!
+//! assert_eq!(3, view.len());
+//! assert_eq!(3, view[0]);
+//! assert_eq!(12, view.iter().sum::<u32>());
+//! ```
+
+use core::mem::MaybeUninit;
+use core::ops::{Bound, RangeBounds};
+
+use crate::{SlidingWindowBase, Iter, Storage};
+use crate::wrapping::WrappingExt as _;
+
+/// A read-only view of a contiguous range of elements of a sliding window.
+///
+/// Indices of the view are relative to the start of the range, and elements are returned in the
+/// order of insertion.
+pub struct WindowView<'a, IT> {
+    items: &'a [MaybeUninit<IT>],
+    read_idx: usize,
+    start: usize,
+    len: usize
+}
+
+impl<'a, IT> Clone for WindowView<'a, IT> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, IT> Copy for WindowView<'a, IT> {}
+
+impl<'a, IT> WindowView<'a, IT> {
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at `idx`, or `None` if the index is out of range.
+    pub fn get(&self, idx: usize) -> Option<&'a IT> {
+        if idx < self.len {
+            let read_from = self.read_idx.wrapping_add_limited(self.start + idx, self.items.len());
+
+            Some(unsafe { &*self.items[read_from].as_ptr() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the elements of the view, in the order of insertion.
+    pub fn iter(&self) -> Iter<'a, IT> {
+        Iter {
+            items: self.items,
+            start: self.read_idx,
+            offset: self.start,
+            count: self.start + self.len
+        }
+    }
+
+    /// Returns a view of a range of this view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn view(&self, range: impl RangeBounds<usize>) -> WindowView<'a, IT> {
+        let (start, end) = resolve_range(range, self.len);
+
+        WindowView {
+            items: self.items,
+            read_idx: self.read_idx,
+            start: self.start + start,
+            len: end - start
+        }
+    }
+
+    /// Divides the view into two at `idx`.
+    ///
+    /// The first view contains the elements before `idx`, the second one the rest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is greater than the length of the view.
+    pub fn split_at(&self, idx: usize) -> (WindowView<'a, IT>, WindowView<'a, IT>) {
+        (self.view(..idx), self.view(idx..))
+    }
+}
+
+impl<'a, IT> core::ops::Index<usize> for WindowView<'a, IT> {
+    type Output = IT;
+    fn index(&self, idx: usize) -> &Self::Output {
+        self.get(idx).expect("Index out of view range")
+    }
+}
+
+impl<'a, IT> IntoIterator for WindowView<'a, IT> {
+    type Item = &'a IT;
+    type IntoIter = Iter<'a, IT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<IT, S> SlidingWindowBase<IT, S>
+    where
+        S: Storage<IT> {
+
+    /// Returns a view of a range of elements, where index 0 is the oldest element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or its start is greater than its end.
+    pub fn view(&self, range: impl RangeBounds<usize>) -> WindowView<'_, IT> {
+        let (start, end) = resolve_range(range, self.count());
+
+        WindowView {
+            items: self.items.as_slice(),
+            read_idx: self.read_idx,
+            start,
+            len: end - start
+        }
+    }
+
+    /// Divides the window into two views at logical index `idx`.
+    ///
+    /// The first view contains the elements older than `idx`, the second one the rest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is greater than [`count`](SlidingWindowBase::count).
+    pub fn split_at(&self, idx: usize) -> (WindowView<'_, IT>, WindowView<'_, IT>) {
+        self.view(..).split_at(idx)
+    }
+}
+
+/// Converts `range` to a `start..end` pair, checking it against `len`.
+fn resolve_range(range: impl RangeBounds<usize>, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => Some(start),
+        Bound::Excluded(&start) => start.checked_add(1),
+        Bound::Unbounded => Some(0)
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1),
+        Bound::Excluded(&end) => Some(end),
+        Bound::Unbounded => Some(len)
+    };
+
+    match (start, end) {
+        (Some(start), Some(end)) if start <= end && end <= len => (start, end),
+        _ => panic!("Range out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SlidingWindow;
+
+    #[test]
+    fn view() {
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+
+        for i in 1..=7 {
+            sw.insert(i);
+        }
+
+        // [3, 4, 5, 6, 7], wrapped in the internal array
+        let view = sw.view(1..4);
+        assert_eq!(3, view.len());
+        assert_eq!(&[4, 5, 6], &view.iter().copied().collect::<Vec<_>>()[..]);
+        assert_eq!(6, view[2]);
+        assert_eq!(None, view.get(3));
+
+        let inner = view.view(1..);
+        assert_eq!(&[5, 6], &inner.into_iter().copied().collect::<Vec<_>>()[..]);
+
+        assert_eq!(5, sw.view(..).len());
+        assert_eq!(7, sw.view(..=4)[4]);
+        assert!(sw.view(5..).is_empty());
+    }
+
+    #[test]
+    fn split_at() {
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+
+        for i in 1..=7 {
+            sw.insert(i);
+        }
+
+        // [3, 4, 5, 6, 7], wrapped in the internal array
+        let (old, recent) = sw.split_at(2);
+        assert_eq!(&[3, 4], &old.iter().copied().collect::<Vec<_>>()[..]);
+        assert_eq!(&[5, 6, 7], &recent.iter().copied().collect::<Vec<_>>()[..]);
+
+        let (a, b) = recent.split_at(3);
+        assert_eq!(3, a.len());
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Range out of bounds")]
+    fn view_inclusive_end_overflow() {
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+        sw.insert(1);
+
+        let _ = sw.view(0..=usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "Range out of bounds")]
+    fn view_out_of_bounds() {
+        let mut sw: SlidingWindow<_, 5> = SlidingWindow::new();
+        sw.insert(1);
+
+        let _ = sw.view(0..2);
+    }
+}
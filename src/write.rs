@@ -0,0 +1,74 @@
+//! Byte windows as write targets, e.g. to keep the last bytes of log output.
+
+use crate::{SlidingWindowBase, Storage};
+
+impl<S> core::fmt::Write for SlidingWindowBase<u8, S>
+    where
+        S: Storage<u8> {
+
+    /// Inserts the bytes of `s` into the window, overwriting the oldest ones when full.
+    ///
+    /// If the window is full, multi-byte characters may be partially overwritten.
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for &byte in s.as_bytes() {
+            self.insert(byte);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<S> embedded_io::ErrorType for SlidingWindowBase<u8, S>
+    where
+        S: Storage<u8> {
+    type Error = core::convert::Infallible;
+}
+
+#[cfg(feature = "embedded-io")]
+impl<S> embedded_io::Write for SlidingWindowBase<u8, S>
+    where
+        S: Storage<u8> {
+
+    /// Inserts every byte of `buf` into the window, overwriting the oldest ones when full.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        for &byte in buf {
+            self.insert(byte);
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SlidingWindow;
+
+    #[test]
+    fn fmt_write() {
+        use core::fmt::Write;
+
+        let mut sw: SlidingWindow<u8, 8> = SlidingWindow::new();
+        let (file, line) = ("main.rs", 42);
+        write!(sw, "panic at {}:{}", file, line).unwrap();
+
+        assert_eq!(b"n.rs:42".as_slice(), &sw.iter().copied().collect::<Vec<_>>()[1..]);
+        assert_eq!(b'i', sw[0]);
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-io")]
+    fn embedded_io_write() {
+        use embedded_io::Write;
+
+        let mut sw: SlidingWindow<u8, 4> = SlidingWindow::new();
+        sw.write_all(b"abcdef").unwrap();
+        sw.flush().unwrap();
+
+        assert_eq!(b"cdef".as_slice(), &sw.iter().copied().collect::<Vec<_>>()[..]);
+    }
+}